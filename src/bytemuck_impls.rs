@@ -0,0 +1,49 @@
+//! [`bytemuck`] support, behind the `bytemuck` feature.
+
+use bytemuck::{Pod, PodCastError};
+
+use crate::{Sliceable, VecSlice};
+
+impl<T: Pod, S: Sliceable<T>> VecSlice<'_, T, S> {
+    /// Reinterprets the elements of the slice as a mutable slice of `U`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is not aligned for `U`, or if its size in bytes is not a multiple of the size of `U`.
+    /// See [`VecSlice::try_cast_vecslice`] for a non-panicking version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0u32; 4];
+    /// let mut slice = vec.vecslice(1..3);
+    ///
+    /// let bytes: &mut [u8] = slice.cast_vecslice();
+    /// bytes[0] = 1;
+    /// bytes[7] = 1;
+    /// assert_eq!(vec, [0, u32::from_ne_bytes([1, 0, 0, 0]), u32::from_ne_bytes([0, 0, 0, 1]), 0]);
+    /// ```
+    pub fn cast_vecslice<U: Pod>(&mut self) -> &mut [U] {
+        bytemuck::cast_slice_mut(self.as_mut_slice())
+    }
+
+    /// Reinterprets the elements of the slice as a mutable slice of `U`,
+    /// or returns an error if the slice is not aligned for `U`, or if its size in bytes is not a multiple of the size of `U`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytemuck::PodCastError;
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0u8; 16];
+    /// let mut slice = vec.vecslice(..6);
+    /// assert_eq!(slice.try_cast_vecslice::<u16>().map(|s| s.len()), Ok(3));
+    /// assert_eq!(slice.try_cast_vecslice::<u32>(), Err(PodCastError::OutputSliceWouldHaveSlop));
+    /// ```
+    pub fn try_cast_vecslice<U: Pod>(&mut self) -> Result<&mut [U], PodCastError> {
+        bytemuck::try_cast_slice_mut(self.as_mut_slice())
+    }
+}
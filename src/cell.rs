@@ -0,0 +1,209 @@
+use core::cell::RefCell;
+use core::ops::RangeBounds;
+
+use crate::{Slice, VecSlice, VecSliceError};
+
+/// Wrapper around a [`Vec`] that lends any number of disjoint ranges at the same time, checking for overlaps at runtime.
+///
+/// Works like a [`RefCell`] where every borrow is a range of the vector:
+/// borrowing a range that overlaps with one that is already borrowed fails with [`VecSliceError::Overlap`].
+/// Each borrowed range can be edited through a [`VecSlice`], including growing and shrinking it,
+/// and the positions of the other borrowed ranges are adjusted when it is returned.
+///
+/// The elements of a borrowed range are moved out of the vector until its [`CellSlice`] is dropped,
+/// so borrowing and returning a range shifts the rest of the vector once.
+/// All positions are relative to the vector as it was before any of the current borrows.
+///
+/// # Examples
+///
+/// ```
+/// use vecslice::VecSliceCell;
+///
+/// let cell = VecSliceCell::new(vec![0, 1, 2, 3, 4, 5]);
+/// let mut head = cell.borrow_mut(..2);
+/// let mut tail = cell.borrow_mut(4..);
+/// assert!(cell.try_borrow_mut(1..3).is_err());
+///
+/// head.slice().push_back(6);
+/// tail.slice().pop_front();
+/// drop((head, tail));
+///
+/// assert_eq!(cell.into_inner(), [0, 1, 6, 2, 3, 5]);
+/// ```
+pub struct VecSliceCell<T> {
+    inner: RefCell<Borrows<T>>,
+}
+
+struct Borrows<T> {
+    vec: Vec<T>,
+    /// Borrowed ranges sorted by their position, as `(id, position in vec, length when borrowed)`.
+    holes: Vec<(usize, usize, usize)>,
+    next_id: usize,
+}
+
+impl<T> Borrows<T> {
+    /// Returns the position of each borrowed range in the vector with all the borrowed elements in place.
+    fn logical_ranges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let mut detached = 0;
+        self.holes.iter().map(move |&(_, pos, len)| {
+            let start = pos + detached;
+            detached += len;
+            (start, start + len)
+        })
+    }
+}
+
+impl<T> VecSliceCell<T> {
+    /// Creates a new [`VecSliceCell`] containing `vec`.
+    pub fn new(vec: Vec<T>) -> Self {
+        VecSliceCell { inner: RefCell::new(Borrows { vec, holes: Vec::new(), next_id: 0 }) }
+    }
+
+    /// Consumes the cell, returning the wrapped vector.
+    pub fn into_inner(self) -> Vec<T> {
+        self.inner.into_inner().vec
+    }
+
+    /// Returns a mutable reference to the wrapped vector.
+    ///
+    /// No range can be borrowed at this point, as the cell is borrowed mutably.
+    pub fn get_mut(&mut self) -> &mut Vec<T> {
+        &mut self.inner.get_mut().vec
+    }
+
+    /// Returns the number of elements in the vector, counting the borrowed ones as they were when borrowed.
+    pub fn len(&self) -> usize {
+        let inner = self.inner.borrow();
+        inner.vec.len() + inner.holes.iter().map(|&(_, _, len)| len).sum::<usize>()
+    }
+
+    /// Returns `true` if the vector contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Borrows the specified range of the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or if it overlaps with a range that is already borrowed.
+    pub fn borrow_mut(&self, range: impl RangeBounds<usize>) -> CellSlice<'_, T> {
+        self.try_borrow_mut(range).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Borrows the specified range of the vector, or returns an error if it is out of bounds
+    /// or overlaps with a range that is already borrowed.
+    ///
+    /// Two empty ranges overlap if they are at the same position, as it wouldn't be clear which one goes first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::{VecSliceCell, VecSliceError};
+    ///
+    /// let cell = VecSliceCell::new(vec![0, 1, 2, 3]);
+    /// let _a = cell.borrow_mut(1..3);
+    /// assert!(cell.try_borrow_mut(3..).is_ok());
+    /// assert_eq!(cell.try_borrow_mut(2..4).unwrap_err(), VecSliceError::Overlap { start: 2, end: 4 });
+    /// assert_eq!(cell.try_borrow_mut(..5).unwrap_err(), VecSliceError::EndOutOfBounds { end: 5, len: 4 });
+    /// ```
+    pub fn try_borrow_mut(&self, range: impl RangeBounds<usize>) -> Result<CellSlice<'_, T>, VecSliceError> {
+        let (start, end) = VecSlice::<T>::try_translate_range(range, self.len())?;
+        let mut inner = self.inner.borrow_mut();
+        let mut index = 0;
+        let mut detached = 0;
+        for (s, e) in inner.logical_ranges() {
+            if (start < e && s < end) || (start == end && s == e && start == s) {
+                return Err(VecSliceError::Overlap { start, end });
+            }
+            if e <= start {
+                index += 1;
+                detached += e - s;
+            }
+        }
+        let pos = start - detached;
+        let id = inner.next_id;
+        inner.next_id += 1;
+        for (_, later, _) in &mut inner.holes[index..] {
+            *later -= end - start;
+        }
+        inner.holes.insert(index, (id, pos, end - start));
+        let buffer = inner.vec.drain(pos..pos + end - start).collect();
+        Ok(CellSlice { cell: self, id, buffer })
+    }
+}
+
+impl<T> From<Vec<T>> for VecSliceCell<T> {
+    fn from(vec: Vec<T>) -> Self {
+        Self::new(vec)
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for VecSliceCell<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let inner = self.inner.borrow();
+        f.debug_struct("VecSliceCell").field("vec", &inner.vec).field("borrowed", &inner.logical_ranges().collect::<Vec<_>>()).finish()
+    }
+}
+
+/// Range borrowed from a [`VecSliceCell`].
+///
+/// This `struct` is created by [`VecSliceCell::borrow_mut`] and [`VecSliceCell::try_borrow_mut`].
+/// The elements are written back into the vector when it is dropped.
+pub struct CellSlice<'c, T> {
+    cell: &'c VecSliceCell<T>,
+    id: usize,
+    buffer: Vec<T>,
+}
+
+impl<T> CellSlice<'_, T> {
+    /// Returns a [`VecSlice`] over the borrowed range, which can grow and shrink freely.
+    pub fn slice(&mut self) -> VecSlice<'_, T> {
+        self.buffer.vecslice(..)
+    }
+
+    /// Returns the number of elements in the borrowed range.
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns `true` if the borrowed range has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Extracts a slice containing the borrowed range.
+    pub fn as_slice(&self) -> &[T] {
+        &self.buffer
+    }
+
+    /// Extracts a mutable slice containing the borrowed range.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.buffer
+    }
+}
+
+impl<T> Drop for CellSlice<'_, T> {
+    fn drop(&mut self) {
+        let mut inner = self.cell.inner.borrow_mut();
+        let index = inner.holes.iter().position(|&(id, _, _)| id == self.id).unwrap();
+        let (_, pos, _) = inner.holes.remove(index);
+        let inserted = self.buffer.len();
+        for (_, later, _) in &mut inner.holes[index..] {
+            *later += inserted;
+        }
+        inner.vec.splice(pos..pos, self.buffer.drain(..));
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for CellSlice<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(&self.buffer).finish()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for CellSlice<'_, T> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.buffer == other
+    }
+}
@@ -0,0 +1,275 @@
+use core::cell::UnsafeCell;
+
+use crate::{CapacityError, Sliceable, TrySliceable};
+
+/// Growable buffer stored as a list of blocks, so inserting or removing in the middle only shifts the elements of one block.
+///
+/// Blocks have between 1 and 2 times the block size elements, and are split when they grow bigger.
+/// Finding a position is *O*(*n* / *block_size*), and inserting or removing an element there is *O*(*block_size*).
+///
+/// Reading the elements through a shared reference, like indexing or iterating a [`VecSlice`](crate::VecSlice) over it,
+/// needs them as a single slice, so it joins all the blocks into the first one, moving the elements after it.
+/// The joined block is only split at the position of the next insertion, so the elements before it are not moved again.
+/// Writing through a mutable reference, like `slice[i] = x`, doesn't join the blocks if the range is inside a single block.
+///
+/// # Examples
+///
+/// ```
+/// use vecslice::{ChunkedVec, Slice};
+///
+/// let mut buffer = ChunkedVec::with_block_size(4);
+/// buffer.extend(0..100);
+///
+/// let mut slice = buffer.vecslice(40..60);
+/// slice.push_front(-1);
+/// slice.remove(10);
+/// assert_eq!(slice.len(), 20);
+/// assert_eq!(slice[..3], [-1, 40, 41]);
+/// assert_eq!(buffer.len(), 100);
+/// ```
+pub struct ChunkedVec<T> {
+    /// Non-empty blocks of elements.
+    ///
+    /// The blocks are only joined through a shared reference by [`ChunkedVec::join`],
+    /// when there can't be any reference to the elements (see its safety comment).
+    blocks: UnsafeCell<Vec<Vec<T>>>,
+    /// Number of elements in all the blocks.
+    len: usize,
+    block_size: usize,
+}
+
+impl<T> ChunkedVec<T> {
+    /// Creates a new, empty [`ChunkedVec`] with the default block size of 4096 elements.
+    pub fn new() -> Self {
+        Self::with_block_size(4096)
+    }
+
+    /// Creates a new, empty [`ChunkedVec`] with blocks of `block_size` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `block_size` is zero.
+    pub fn with_block_size(block_size: usize) -> Self {
+        assert!(block_size != 0, "block size must be non-zero");
+        ChunkedVec { blocks: UnsafeCell::new(Vec::new()), len: 0, block_size }
+    }
+
+    /// Returns the number of elements each block holds after being split.
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    /// Returns the number of elements in the buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the buffer contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Appends an element to the back of the buffer.
+    pub fn push(&mut self, element: T) {
+        let block_size = self.block_size;
+        self.len += 1;
+        let blocks = self.blocks.get_mut();
+        match blocks.last_mut() {
+            Some(last) if last.len() < 2 * block_size => last.push(element),
+            _ => {
+                let mut block = Vec::with_capacity(block_size);
+                block.push(element);
+                blocks.push(block);
+            }
+        }
+    }
+
+    /// Consumes the buffer, returning a [`Vec`] with its elements.
+    pub fn into_vec(mut self) -> Vec<T> {
+        self.join_mut();
+        self.blocks.into_inner().pop().unwrap_or_default()
+    }
+
+    fn blocks(&self) -> &Vec<Vec<T>> {
+        // SAFETY: No mutable reference to `blocks` exists while `&self` is alive, except inside `join`,
+        // which doesn't call any other method.
+        unsafe { &*self.blocks.get() }
+    }
+
+    /// Returns the block containing `index` and the position of `index` inside it.
+    ///
+    /// `len` is part of the last block.
+    fn locate(&mut self, mut index: usize) -> (usize, usize) {
+        let blocks = self.blocks.get_mut();
+        for (i, block) in blocks.iter().enumerate() {
+            if index < block.len() || i == blocks.len() - 1 {
+                return (i, index);
+            }
+            index -= block.len();
+        }
+        (0, index)
+    }
+
+    fn insert_at(&mut self, index: usize, element: T) {
+        let len = self.len();
+        assert!(index <= len, "insertion index (is {index}) should be <= len (is {len})");
+        let (i, offset) = self.locate(index);
+        let block_size = self.block_size;
+        self.len += 1;
+        let blocks = self.blocks.get_mut();
+        if blocks.is_empty() {
+            blocks.push(Vec::with_capacity(block_size));
+        }
+        if blocks[i].len() > 2 * block_size {
+            // A block joined by a read, only the elements after the insertion point are moved to a new block
+            let after = blocks[i].split_off(offset);
+            blocks[i].push(element);
+            if !after.is_empty() {
+                blocks.insert(i + 1, after);
+            }
+            return;
+        }
+        blocks[i].insert(offset, element);
+        if blocks[i].len() > 2 * block_size {
+            // Split the block into pieces of `block_size` elements
+            let mut block = blocks.remove(i);
+            let mut pieces = Vec::with_capacity(block.len() / block_size + 1);
+            while block.len() > 2 * block_size {
+                pieces.push(block.split_off(block.len() - block_size));
+            }
+            pieces.push(block);
+            pieces.reverse();
+            blocks.splice(i..i, pieces);
+        }
+    }
+
+    /// Joins all the blocks into the first one through a shared reference.
+    fn join(&self) {
+        // SAFETY: The only references to the elements that can be created through a shared reference are the ones
+        // returned by `as_slice`, which joins the blocks before creating them. Blocks can only be split with a mutable
+        // reference, so if there is more than one block, there can't be any reference to the elements alive,
+        // and `blocks` can be mutated.
+        // `ChunkedVec` isn't `Sync`, and no user code is called while `blocks` is borrowed.
+        if self.blocks().len() <= 1 {
+            return;
+        }
+        Self::join_blocks(unsafe { &mut *self.blocks.get() });
+    }
+
+    fn join_mut(&mut self) {
+        Self::join_blocks(self.blocks.get_mut());
+    }
+
+    /// Appends the elements of every block to the first one, which keeps its allocation if it has enough capacity.
+    fn join_blocks(blocks: &mut Vec<Vec<T>>) {
+        if blocks.len() > 1 {
+            let rest: Vec<Vec<T>> = blocks.drain(1..).collect();
+            blocks[0].extend(rest.into_iter().flatten());
+        }
+    }
+}
+
+impl<T> Sliceable<T> for ChunkedVec<T> {
+    fn len(&self) -> usize {
+        ChunkedVec::len(self)
+    }
+
+    fn insert(&mut self, index: usize, element: T) {
+        self.insert_at(index, element);
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        assert!(index < len, "removal index (is {index}) should be < len (is {len})");
+        let (i, offset) = self.locate(index);
+        self.len -= 1;
+        let blocks = self.blocks.get_mut();
+        let element = blocks[i].remove(offset);
+        if blocks[i].is_empty() {
+            blocks.remove(i);
+        }
+        element
+    }
+
+    fn as_slice(&self) -> &[T] {
+        self.join();
+        self.blocks().first().map_or(&[], Vec::as_slice)
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self.join_mut();
+        self.blocks.get_mut().first_mut().map_or(&mut [], Vec::as_mut_slice)
+    }
+
+    fn as_mut_range(&mut self, start: usize, end: usize) -> &mut [T] {
+        assert!(start <= end && end <= self.len, "range {start}..{end} out of range for buffer of length {}", self.len);
+        if start == end {
+            return &mut [];
+        }
+        let (i, offset) = self.locate(start);
+        if offset + (end - start) <= self.blocks.get_mut()[i].len() {
+            return &mut self.blocks.get_mut()[i][offset..offset + (end - start)];
+        }
+        &mut self.as_mut_slice()[start..end]
+    }
+
+    fn clear_range(&mut self, start: usize, end: usize) {
+        let mut offset = 0;
+        let mut removed = 0;
+        self.blocks.get_mut().retain_mut(|block| {
+            let block_start = offset;
+            offset += block.len();
+            let (from, to) = (start.max(block_start), end.min(offset));
+            if from < to {
+                block.drain(from - block_start..to - block_start);
+                removed += to - from;
+            }
+            !block.is_empty()
+        });
+        self.len -= removed;
+    }
+}
+
+impl<T> TrySliceable<T> for ChunkedVec<T> {
+    fn try_insert(&mut self, index: usize, element: T) -> Result<(), CapacityError<T>> {
+        self.insert(index, element);
+        Ok(())
+    }
+}
+
+impl<T> Default for ChunkedVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Extend<T> for ChunkedVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for element in iter {
+            self.push(element);
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for ChunkedVec<T> {
+    fn from(vec: Vec<T>) -> Self {
+        let mut chunked = Self::new();
+        chunked.len = vec.len();
+        if !vec.is_empty() {
+            chunked.blocks.get_mut().push(vec);
+        }
+        chunked
+    }
+}
+
+impl<T> FromIterator<T> for ChunkedVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(Vec::from_iter(iter))
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for ChunkedVec<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
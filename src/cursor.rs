@@ -0,0 +1,145 @@
+use crate::{Sliceable, VecSlice};
+
+/// Cursor over a [`VecSlice`] that can move back and forth and edit the slice around its position.
+///
+/// This `struct` is created by [`VecSlice::cursor`].
+///
+/// The cursor points to an element of the slice, or to the "ghost" position `len` past the last one,
+/// and keeps pointing to the same place after inserting or removing elements, so sequential edits
+/// don't need to recompute any index.
+///
+/// # Examples
+///
+/// ```
+/// use vecslice::Slice;
+///
+/// // Tape of a small interpreter
+/// let mut tape = vec![0, 0, 0];
+/// let mut slice = tape.vecslice(..);
+/// let mut cursor = slice.cursor(0);
+///
+/// *cursor.peek_mut().unwrap() += 1;
+/// cursor.move_right();
+/// cursor.insert_before(7);
+/// cursor.insert_after(8);
+/// assert_eq!(cursor.position(), 2);
+/// assert_eq!(cursor.peek(), Some(&0));
+/// assert_eq!(cursor.remove_current(), Some(0));
+/// assert_eq!(cursor.peek(), Some(&8));
+///
+/// assert_eq!(tape, [1, 7, 8, 0]);
+/// ```
+pub struct Cursor<'s, 'a, T, S: Sliceable<T> = Vec<T>> {
+    slice: &'s mut VecSlice<'a, T, S>,
+    pos: usize,
+}
+
+impl<'s, 'a, T, S: Sliceable<T>> Cursor<'s, 'a, T, S> {
+    pub(crate) fn new(slice: &'s mut VecSlice<'a, T, S>, pos: usize) -> Self {
+        assert!(pos <= slice.len(), "cursor position (is {pos}) should be <= len (is {})", slice.len());
+        Cursor { slice, pos }
+    }
+
+    /// Returns the underlying slice.
+    pub fn into_inner(self) -> &'s mut VecSlice<'a, T, S> {
+        self.slice
+    }
+
+    /// Returns the position of the cursor in the slice, which is `len` if it is past the last element.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Moves the cursor to position `pos`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pos > len`.
+    pub fn seek(&mut self, pos: usize) {
+        assert!(pos <= self.slice.len(), "cursor position (is {pos}) should be <= len (is {})", self.slice.len());
+        self.pos = pos;
+    }
+
+    /// Moves the cursor to the previous element, returning `false` if it already was at the start of the slice.
+    pub fn move_left(&mut self) -> bool {
+        if self.pos == 0 {
+            return false;
+        }
+        self.pos -= 1;
+        true
+    }
+
+    /// Moves the cursor to the next element, returning `false` if it already was past the last element.
+    pub fn move_right(&mut self) -> bool {
+        if self.pos == self.slice.len() {
+            return false;
+        }
+        self.pos += 1;
+        true
+    }
+
+    /// Returns a reference to the element the cursor points to, or [`None`] if it is past the last element.
+    pub fn peek(&self) -> Option<&T> {
+        self.slice.get(self.pos)
+    }
+
+    /// Returns a mutable reference to the element the cursor points to, or [`None`] if it is past the last element.
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.slice.get_mut(self.pos)
+    }
+
+    /// Returns a reference to the element before the cursor, or [`None`] if it is at the start of the slice.
+    pub fn peek_prev(&self) -> Option<&T> {
+        self.slice.get(self.pos.checked_sub(1)?)
+    }
+
+    /// Inserts an element before the cursor, which keeps pointing to the same element.
+    pub fn insert_before(&mut self, element: T) {
+        self.slice.insert(self.pos, element);
+        self.pos += 1;
+    }
+
+    /// Inserts an element after the element the cursor points to, or at the end of the slice if it is past the last one.
+    ///
+    /// The cursor keeps pointing to the same element.
+    pub fn insert_after(&mut self, element: T) {
+        let index = (self.pos + 1).min(self.slice.len());
+        self.slice.insert(index, element);
+    }
+
+    /// Removes the element the cursor points to and returns it, or [`None`] if it is past the last element.
+    ///
+    /// The cursor then points to the element that was after the removed one.
+    pub fn remove_current(&mut self) -> Option<T> {
+        if self.pos == self.slice.len() {
+            return None;
+        }
+        Some(self.slice.remove(self.pos))
+    }
+
+    /// Removes the element the cursor points to and all the elements after it, returning them in a new [`Vec`].
+    ///
+    /// The cursor is left past the last element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4];
+    /// let mut slice = vec.vecslice(..4);
+    /// let mut cursor = slice.cursor(1);
+    /// assert_eq!(cursor.split_rest(), [1, 2, 3]);
+    /// assert_eq!(cursor.peek(), None);
+    /// assert_eq!(vec, [0, 4]);
+    /// ```
+    pub fn split_rest(&mut self) -> Vec<T> {
+        self.slice.split_off(self.pos)
+    }
+}
+
+impl<T: core::fmt::Debug, S: Sliceable<T>> core::fmt::Debug for Cursor<'_, '_, T, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Cursor").field("slice", &self.slice.as_slice()).field("position", &self.pos).finish()
+    }
+}
@@ -0,0 +1,181 @@
+use core::marker::PhantomData;
+use core::ops::Range;
+
+use crate::{CapacityError, Sliceable, TrySliceable, VecSlice};
+
+/// Buffer wrapper that records the smallest range of positions that may have been modified.
+///
+/// Every insertion and removal marks the positions of the shifted elements, up to the old or new end of the buffer,
+/// and every mutable access made by a [`VecSlice`] marks the accessed range,
+/// so downstream systems (GPU uploads, caches) can update only that range instead of diffing the whole buffer.
+///
+/// The modified range can be queried through [`DirtyTracker::modified_range`] or [`VecSlice::modified_range`].
+///
+/// # Examples
+///
+/// ```
+/// use vecslice::{DirtyTracker, Slice};
+///
+/// let mut buffer = DirtyTracker::new(vec![0; 100]);
+/// let mut slice = buffer.vecslice(10..20);
+///
+/// slice[2] = 1;
+/// slice[5] = 2;
+/// assert_eq!(slice.modified_range(), Some(12..16));
+///
+/// // The range keeps growing until it is taken
+/// assert_eq!(buffer.take_modified_range(), Some(12..16));
+/// assert_eq!(buffer.modified_range(), None);
+///
+/// // Replacing elements in place only marks them
+/// buffer.vecslice(40..50).overwrite_range(..3, &[7, 8, 9]);
+/// assert_eq!(buffer.take_modified_range(), Some(40..43));
+///
+/// // Shifting the buffer marks every shifted element
+/// buffer.vecslice(90..).push_front(3);
+/// assert_eq!(buffer.take_modified_range(), Some(90..101));
+/// ```
+pub struct DirtyTracker<T, S: Sliceable<T> = Vec<T>> {
+    inner: S,
+    modified: Option<Range<usize>>,
+    marker: PhantomData<T>,
+}
+
+impl<T, S: Sliceable<T>> DirtyTracker<T, S> {
+    /// Wraps `inner`, with no position marked as modified.
+    pub fn new(inner: S) -> Self {
+        DirtyTracker { inner, modified: None, marker: PhantomData }
+    }
+
+    /// Returns a reference to the wrapped buffer.
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Consumes the tracker, returning the wrapped buffer.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    /// Returns the smallest range containing every position that may have been modified,
+    /// or [`None`] if nothing has been modified.
+    ///
+    /// The range can extend past the current length of the buffer if it has shrunk.
+    pub fn modified_range(&self) -> Option<Range<usize>> {
+        self.modified.clone()
+    }
+
+    /// Returns the modified range, like [`DirtyTracker::modified_range`], and starts tracking again from scratch.
+    pub fn take_modified_range(&mut self) -> Option<Range<usize>> {
+        self.modified.take()
+    }
+
+    fn mark(&mut self, start: usize, end: usize) {
+        if start >= end {
+            return;
+        }
+        self.modified = Some(match self.modified.take() {
+            Some(range) => range.start.min(start)..range.end.max(end),
+            None => start..end,
+        });
+    }
+}
+
+impl<T, S: Sliceable<T>> Sliceable<T> for DirtyTracker<T, S> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn insert(&mut self, index: usize, element: T) {
+        self.inner.insert(index, element);
+        self.mark(index, self.inner.len());
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        let len = self.inner.len();
+        let element = self.inner.remove(index);
+        self.mark(index, len);
+        element
+    }
+
+    fn as_slice(&self) -> &[T] {
+        self.inner.as_slice()
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self.mark(0, self.inner.len());
+        self.inner.as_mut_slice()
+    }
+
+    fn as_mut_range(&mut self, start: usize, end: usize) -> &mut [T] {
+        // An invalid range panics below, don't mark it
+        if end <= self.inner.len() {
+            self.mark(start, end);
+        }
+        self.inner.as_mut_range(start, end)
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        self.inner.capacity()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional);
+    }
+
+    fn clear_range(&mut self, start: usize, end: usize) {
+        let len = self.inner.len();
+        self.inner.clear_range(start, end);
+        if start < end {
+            self.mark(start, len);
+        }
+    }
+
+    fn splice_range<I: IntoIterator<Item = T>>(&mut self, start: usize, end: usize, replace_with: I) -> usize {
+        let len = self.inner.len();
+        let inserted = self.inner.splice_range(start, end, replace_with);
+        if inserted == end - start {
+            self.mark(start, end);
+        } else {
+            self.mark(start, len.max(self.inner.len()));
+        }
+        inserted
+    }
+}
+
+impl<T, S: TrySliceable<T>> TrySliceable<T> for DirtyTracker<T, S> {
+    fn try_insert(&mut self, index: usize, element: T) -> Result<(), CapacityError<T>> {
+        let len = self.inner.len();
+        self.inner.try_insert(index, element)?;
+        self.mark(index, len + 1);
+        Ok(())
+    }
+}
+
+impl<T, S: Sliceable<T> + Default> Default for DirtyTracker<T, S> {
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+
+impl<T, S: Sliceable<T>> From<S> for DirtyTracker<T, S> {
+    fn from(inner: S) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl<T: core::fmt::Debug, S: Sliceable<T>> core::fmt::Debug for DirtyTracker<T, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DirtyTracker").field("inner", &self.inner.as_slice()).field("modified", &self.modified).finish()
+    }
+}
+
+impl<T, S: Sliceable<T>> VecSlice<'_, T, DirtyTracker<T, S>> {
+    /// Returns the smallest range of absolute positions in the underlying buffer that may have been modified,
+    /// or [`None`] if nothing has been modified.
+    ///
+    /// See [`DirtyTracker`] for more information.
+    pub fn modified_range(&self) -> Option<Range<usize>> {
+        self.original.modified_range()
+    }
+}
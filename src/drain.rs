@@ -0,0 +1,104 @@
+use crate::Sliceable;
+
+/// A draining iterator for [`VecSlice`](crate::VecSlice).
+///
+/// This `struct` is created by [`VecSlice::drain`](crate::VecSlice::drain).
+///
+/// The drained elements are moved out of the buffer when the iterator is created,
+/// and the elements after them are shifted only once,
+/// so draining `m` elements from a buffer of `n` elements is *O*(*n* + *m*).
+pub struct Drain<'a, T, S: Sliceable<T> = Vec<T>> {
+    original: &'a mut S,
+    /// Position of the drained range in `original`.
+    start: usize,
+    /// Drained elements not yet returned, in order.
+    drained: std::vec::IntoIter<T>,
+    /// End of the slice that was drained, updated by [`Drain::keep_rest`].
+    slice_end: Option<&'a mut usize>,
+}
+
+impl<'a, T, S: Sliceable<T>> Drain<'a, T, S> {
+    pub(crate) fn new(original: &'a mut S, start: usize, end: usize, slice_end: Option<&'a mut usize>) -> Self {
+        // Move the drained elements to the end of the buffer in reverse order,
+        // so every element can be removed without shifting the rest of the buffer
+        let len = original.len();
+        let slice = original.as_mut_range(start, len);
+        slice.rotate_left(end - start);
+        slice[len - end..].reverse();
+        let drained: Vec<T> = (start..end).map(|_| original.remove(original.len() - 1)).collect();
+        Drain { original, start, drained: drained.into_iter(), slice_end }
+    }
+
+    /// Returns the remaining items of this iterator as a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let mut slice = vec.vecslice(..);
+    /// let mut drain = slice.drain(1..);
+    /// assert_eq!(drain.as_slice(), &[1, 2, 3]);
+    /// let _ = drain.next().unwrap();
+    /// assert_eq!(drain.as_slice(), &[2, 3]);
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        self.drained.as_slice()
+    }
+
+    /// Keeps the unyielded elements in the slice, in their original position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4];
+    /// let mut slice = vec.vecslice(1..);
+    /// let mut drain = slice.drain(..3);
+    ///
+    /// assert_eq!(drain.next(), Some(1));
+    /// assert_eq!(drain.next_back(), Some(3));
+    ///
+    /// // This call keeps `2` in the slice.
+    /// drain.keep_rest();
+    ///
+    /// assert_eq!(slice, [2, 4]);
+    /// assert_eq!(vec, [0, 2, 4]);
+    /// ```
+    pub fn keep_rest(self) {
+        let kept = self.original.splice_range(self.start, self.start, self.drained);
+        if let Some(slice_end) = self.slice_end {
+            *slice_end += kept;
+        }
+    }
+}
+
+impl<T, S: Sliceable<T>> Iterator for Drain<'_, T, S> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.drained.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.drained.size_hint()
+    }
+}
+
+impl<T, S: Sliceable<T>> DoubleEndedIterator for Drain<'_, T, S> {
+    fn next_back(&mut self) -> Option<T> {
+        self.drained.next_back()
+    }
+}
+
+impl<T: core::fmt::Debug, S: Sliceable<T>> core::fmt::Debug for Drain<'_, T, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Drain").field(&self.as_slice()).finish()
+    }
+}
+
+impl<T, S: Sliceable<T>> ExactSizeIterator for Drain<'_, T, S> {}
+
+impl<T, S: Sliceable<T>> core::iter::FusedIterator for Drain<'_, T, S> {}
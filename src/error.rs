@@ -0,0 +1,66 @@
+/// Error returned by the fallible `try_*` methods of [`VecSlice`](crate::VecSlice) and [`Slice`](crate::Slice).
+///
+/// All positions are relative to the slice (or collection) the operation was performed on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VecSliceError {
+    /// The start of the range is greater than the length.
+    StartOutOfBounds { start: usize, len: usize },
+    /// The end of the range is greater than the length.
+    EndOutOfBounds { end: usize, len: usize },
+    /// The start of the range is greater than its end.
+    InvertedRange { start: usize, end: usize },
+    /// The index is out of bounds.
+    IndexOutOfBounds { index: usize, len: usize },
+    /// The range overlaps with one that is already borrowed from a [`VecSliceCell`](crate::VecSliceCell).
+    Overlap { start: usize, end: usize },
+    /// The [`TrackedIndex`](crate::TrackedIndex) was invalidated by a structural change of the buffer, or belongs to a different slice.
+    StaleIndex,
+}
+
+impl core::fmt::Display for VecSliceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            VecSliceError::StartOutOfBounds { start, len } => write!(f, "range start index {start} out of range for slice of length {len}"),
+            VecSliceError::EndOutOfBounds { end, len } => write!(f, "range end index {end} out of range for slice of length {len}"),
+            VecSliceError::InvertedRange { start, end } => write!(f, "slice index starts at {start} but ends at {end}"),
+            VecSliceError::IndexOutOfBounds { index, len } => write!(f, "index {index} out of range for slice of length {len}"),
+            VecSliceError::Overlap { start, end } => write!(f, "range {start}..{end} overlaps with an already borrowed range"),
+            VecSliceError::StaleIndex => write!(f, "tracked index is no longer valid for this slice"),
+        }
+    }
+}
+
+impl std::error::Error for VecSliceError {}
+
+/// Error returned when inserting into a collection that is already full, holding the element that couldn't be inserted.
+///
+/// Returned by [`TrySliceable::try_insert`](crate::TrySliceable::try_insert) and the `try_push_*` methods of [`VecSlice`](crate::VecSlice).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CapacityError<T> {
+    element: T,
+}
+
+impl<T> CapacityError<T> {
+    /// Creates a new [`CapacityError`] holding `element`.
+    pub fn new(element: T) -> Self {
+        CapacityError { element }
+    }
+
+    /// Returns a reference to the element that couldn't be inserted.
+    pub fn element(&self) -> &T {
+        &self.element
+    }
+
+    /// Consumes the error, returning the element that couldn't be inserted.
+    pub fn into_element(self) -> T {
+        self.element
+    }
+}
+
+impl<T> core::fmt::Display for CapacityError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "insufficient capacity")
+    }
+}
+
+impl<T: core::fmt::Debug> std::error::Error for CapacityError<T> {}
@@ -0,0 +1,35 @@
+use crate::{Sliceable, VecSlice};
+
+/// An iterator which uses a closure to determine if an element should be removed.
+///
+/// This `struct` is created by [`VecSlice::extract_if`].
+pub struct ExtractIf<'s, 'a, T, S: Sliceable<T>, F: FnMut(&mut T) -> bool> {
+    slice: &'s mut VecSlice<'a, T, S>,
+    /// Index of the next element to check.
+    index: usize,
+    pred: F,
+}
+
+impl<'s, 'a, T, S: Sliceable<T>, F: FnMut(&mut T) -> bool> ExtractIf<'s, 'a, T, S, F> {
+    pub(crate) fn new(slice: &'s mut VecSlice<'a, T, S>, pred: F) -> Self {
+        ExtractIf { slice, index: 0, pred }
+    }
+}
+
+impl<T, S: Sliceable<T>, F: FnMut(&mut T) -> bool> Iterator for ExtractIf<'_, '_, T, S, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while self.index < self.slice.len() {
+            if (self.pred)(&mut self.slice[self.index]) {
+                return Some(self.slice.remove(self.index));
+            }
+            self.index += 1;
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.slice.len() - self.index))
+    }
+}
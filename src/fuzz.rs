@@ -0,0 +1,93 @@
+//! Operations on a [`VecSlice`] that can be generated by a fuzzer, behind the `arbitrary` or `proptest` features.
+//!
+//! A fuzz target can apply the same sequence of [`Op`]s to a [`VecSlice`] with [`apply`]
+//! and to a plain [`Vec`] with [`apply_model`], and check that both return the same elements
+//! and end up with the same contents.
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "arbitrary")] {
+//! use arbitrary::{Arbitrary, Unstructured};
+//! use vecslice::Slice;
+//! use vecslice::fuzz::{apply, apply_model, Op};
+//!
+//! let data = [7, 1, 3, 9, 4, 2, 8, 0, 5, 6, 2, 4, 1, 3, 3, 7];
+//! let mut u = Unstructured::new(&data);
+//!
+//! let mut vec = vec![10, 20, 30, 40];
+//! let mut slice = vec.vecslice(1..3);
+//! let mut model = vec![20, 30];
+//! while !u.is_empty() {
+//!     let op = Op::<u8>::arbitrary(&mut u).unwrap();
+//!     assert_eq!(apply(&mut slice, op.clone()), apply_model(&mut model, op));
+//!     assert_eq!(slice, model);
+//! }
+//! # }
+//! ```
+
+use crate::{Sliceable, VecSlice};
+
+/// Operation on a slice, with arguments that are reduced to valid positions when applied,
+/// so any generated value can be applied to any slice.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Op<T> {
+    /// Inserts the element at the back.
+    PushBack(T),
+    /// Inserts the element at the front.
+    PushFront(T),
+    /// Removes the last element.
+    PopBack,
+    /// Removes the first element.
+    PopFront,
+    /// Inserts the element at `index % (len + 1)`.
+    Insert { index: usize, element: T },
+    /// Removes the element at `index % len`, if the slice is not empty.
+    Remove { index: usize },
+    /// Removes the elements between `start % (len + 1)` and `end % (len + 1)`, in any order.
+    Drain { start: usize, end: usize },
+}
+
+/// Reduces the arguments of `op` to valid positions for a slice of length `len`.
+fn normalize<T>(op: Op<T>, len: usize) -> Option<Op<T>> {
+    Some(match op {
+        Op::Insert { index, element } => Op::Insert { index: index % (len + 1), element },
+        Op::Remove { index } => Op::Remove { index: index.checked_rem(len)? },
+        Op::Drain { start, end } => {
+            let (start, end) = (start % (len + 1), end % (len + 1));
+            Op::Drain { start: start.min(end), end: start.max(end) }
+        }
+        op => op,
+    })
+}
+
+/// Applies `op` to `slice`, returning the removed elements.
+pub fn apply<T, S: Sliceable<T>>(slice: &mut VecSlice<'_, T, S>, op: Op<T>) -> Vec<T> {
+    match normalize(op, slice.len()) {
+        Some(Op::PushBack(element)) => slice.push_back(element),
+        Some(Op::PushFront(element)) => slice.push_front(element),
+        Some(Op::PopBack) => return slice.pop_back().into_iter().collect(),
+        Some(Op::PopFront) => return slice.pop_front().into_iter().collect(),
+        Some(Op::Insert { index, element }) => slice.insert(index, element),
+        Some(Op::Remove { index }) => return vec![slice.remove(index)],
+        Some(Op::Drain { start, end }) => return slice.drain(start..end).collect(),
+        None => {}
+    }
+    Vec::new()
+}
+
+/// Applies `op` to `model`, returning the removed elements, as [`apply`] does for a [`VecSlice`].
+pub fn apply_model<T>(model: &mut Vec<T>, op: Op<T>) -> Vec<T> {
+    match normalize(op, model.len()) {
+        Some(Op::PushBack(element)) => model.push(element),
+        Some(Op::PushFront(element)) => model.insert(0, element),
+        Some(Op::PopBack) => return model.pop().into_iter().collect(),
+        Some(Op::PopFront) if !model.is_empty() => return vec![model.remove(0)],
+        Some(Op::Insert { index, element }) => model.insert(index, element),
+        Some(Op::Remove { index }) => return vec![model.remove(index)],
+        Some(Op::Drain { start, end }) => return model.drain(start..end).collect(),
+        Some(Op::PopFront) | None => {}
+    }
+    Vec::new()
+}
@@ -0,0 +1,177 @@
+use core::cell::UnsafeCell;
+
+use crate::{CapacityError, Sliceable, TrySliceable};
+
+/// Growable buffer with a movable gap, optimized for repeated insertions and removals around the same position.
+///
+/// The elements before the gap and the ones after it are stored separately,
+/// so inserting or removing at the gap is *O*(1), and moving the gap is *O*(distance).
+///
+/// Reading the elements through a shared reference, like indexing or iterating a [`VecSlice`](crate::VecSlice) over it,
+/// needs them as a single slice, so it closes the gap by moving it to the end of the buffer, which is *O*(*n* - *gap*),
+/// and the next edit moves it back.
+/// Writing through a mutable reference, like `slice[i] = x`, only moves the gap past the end of the written range, if it is after the gap.
+///
+/// When used as the buffer of a [`VecSlice`](crate::VecSlice), a burst of edits around the same position
+/// doesn't shift the rest of the buffer until the elements are read again.
+///
+/// # Examples
+///
+/// ```
+/// use vecslice::{GapBuffer, Slice};
+///
+/// let mut buffer = GapBuffer::from(vec![0, 1, 2, 3]);
+/// let mut slice = buffer.vecslice(1..3);
+///
+/// // Every insertion happens at the gap
+/// for i in 4..8 {
+///     slice.insert(1 + i - 4, i);
+/// }
+/// assert_eq!(slice, [1, 4, 5, 6, 7, 2]);
+/// assert_eq!(buffer.into_vec(), [0, 1, 4, 5, 6, 7, 2, 3]);
+/// ```
+pub struct GapBuffer<T> {
+    /// Elements before and after the gap, the latter in reverse order.
+    ///
+    /// The gap is only moved through a shared reference by [`GapBuffer::close`],
+    /// when there can't be any reference to the elements (see its safety comment).
+    inner: UnsafeCell<(Vec<T>, Vec<T>)>,
+}
+
+impl<T> GapBuffer<T> {
+    /// Creates a new, empty [`GapBuffer`].
+    pub fn new() -> Self {
+        GapBuffer { inner: UnsafeCell::new((Vec::new(), Vec::new())) }
+    }
+
+    /// Returns the number of elements in the buffer.
+    pub fn len(&self) -> usize {
+        // SAFETY: No mutable reference to `inner` exists while `&self` is alive, except inside `close`,
+        // which doesn't call any other method.
+        let (before, after) = unsafe { &*self.inner.get() };
+        before.len() + after.len()
+    }
+
+    /// Returns `true` if the buffer contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the position of the gap.
+    pub fn gap(&self) -> usize {
+        // SAFETY: See `len`
+        unsafe { &*self.inner.get() }.0.len()
+    }
+
+    /// Moves the gap to position `index`, shifting the elements in between.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn move_gap(&mut self, index: usize) {
+        assert!(index <= self.len(), "gap index (is {index}) should be <= len (is {})", self.len());
+        let (before, after) = self.inner.get_mut();
+        while before.len() > index {
+            after.extend(before.pop());
+        }
+        while before.len() < index {
+            before.extend(after.pop());
+        }
+    }
+
+    /// Moves the gap to the end of the buffer through a shared reference.
+    fn close(&self) {
+        // SAFETY: The only references to the elements that can be created through a shared reference are the ones
+        // returned by `as_slice`, which closes the gap before creating them. Elements can only be moved after the gap
+        // with a mutable reference, so if there are elements after the gap, there can't be any reference to the
+        // elements alive, and `inner` can be mutated.
+        // `GapBuffer` isn't `Sync`, and no user code is called while `inner` is borrowed.
+        if unsafe { &*self.inner.get() }.1.is_empty() {
+            return;
+        }
+        let (before, after) = unsafe { &mut *self.inner.get() };
+        before.reserve(after.len());
+        while let Some(element) = after.pop() {
+            before.push(element);
+        }
+    }
+
+    /// Consumes the buffer, returning a [`Vec`] with its elements.
+    pub fn into_vec(mut self) -> Vec<T> {
+        self.move_gap(self.len());
+        self.inner.into_inner().0
+    }
+}
+
+impl<T> Sliceable<T> for GapBuffer<T> {
+    fn len(&self) -> usize {
+        GapBuffer::len(self)
+    }
+
+    fn insert(&mut self, index: usize, element: T) {
+        self.move_gap(index);
+        self.inner.get_mut().0.push(element);
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len(), "removal index (is {index}) should be < len (is {})", self.len());
+        self.move_gap(index);
+        self.inner.get_mut().1.pop().unwrap()
+    }
+
+    fn as_slice(&self) -> &[T] {
+        self.close();
+        // SAFETY: See `len`
+        &unsafe { &*self.inner.get() }.0
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self.close();
+        &mut self.inner.get_mut().0
+    }
+
+    fn as_mut_range(&mut self, start: usize, end: usize) -> &mut [T] {
+        assert!(start <= end && end <= self.len(), "range {start}..{end} out of range for buffer of length {}", self.len());
+        if end > self.gap() {
+            self.move_gap(end);
+        }
+        &mut self.inner.get_mut().0[start..end]
+    }
+
+    fn clear_range(&mut self, start: usize, end: usize) {
+        self.move_gap(start);
+        let after = &mut self.inner.get_mut().1;
+        after.truncate(after.len() - (end - start));
+    }
+}
+
+impl<T> TrySliceable<T> for GapBuffer<T> {
+    fn try_insert(&mut self, index: usize, element: T) -> Result<(), CapacityError<T>> {
+        self.insert(index, element);
+        Ok(())
+    }
+}
+
+impl<T> Default for GapBuffer<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<Vec<T>> for GapBuffer<T> {
+    fn from(vec: Vec<T>) -> Self {
+        GapBuffer { inner: UnsafeCell::new((vec, Vec::new())) }
+    }
+}
+
+impl<T> FromIterator<T> for GapBuffer<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(Vec::from_iter(iter))
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for GapBuffer<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
@@ -0,0 +1,132 @@
+use core::ops::RangeBounds;
+
+use crate::{Sliceable, VecSlice};
+
+/// Contiguous copy of a range of an [`im::Vector`](https://docs.rs/im/15/im/struct.Vector.html), used as the buffer of a [`VecSlice`].
+///
+/// A persistent vector stores its elements in a tree of chunks, so it can't be sliced directly.
+/// The mirror copies the range into a [`Vec`] when created, which is *O*(*range*), and every edit is made on the copy.
+/// The copy is written back into the vector by [`VectorMirror::sync`] or when the mirror is dropped,
+/// replacing only the mirrored range, so the rest of the vector keeps sharing its structure with earlier snapshots.
+///
+/// Positions of the mirror are relative to the start of the mirrored range.
+///
+/// # Examples
+///
+/// ```
+/// use vecslice::{Slice, VectorMirror};
+///
+/// let mut vector: im::Vector<i32> = (0..10).collect();
+/// let before = vector.clone();
+///
+/// let mut mirror = VectorMirror::new(&mut vector, 2..6);
+/// let mut slice = mirror.vecslice(1..3);
+/// slice.push_back(10);
+/// slice.pop_front();
+/// assert_eq!(mirror.snapshot(), im::vector![0, 1, 2, 4, 10, 5, 6, 7, 8, 9]);
+///
+/// drop(mirror);
+/// assert_eq!(vector, im::vector![0, 1, 2, 4, 10, 5, 6, 7, 8, 9]);
+/// assert_eq!(before, (0..10).collect());
+/// ```
+pub struct VectorMirror<'a, T: Clone> {
+    vector: &'a mut im::Vector<T>,
+    /// Start of the mirrored range in `vector`.
+    start: usize,
+    /// Length of the mirrored range in `vector`, the elements of `mirror` written back last.
+    synced: usize,
+    mirror: Vec<T>,
+    /// Whether `mirror` may differ from the mirrored range of `vector`.
+    modified: bool,
+}
+
+impl<'a, T: Clone> VectorMirror<'a, T> {
+    /// Copies the elements of `vector` in `range` into a new mirror.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of the bounds of the vector.
+    pub fn new(vector: &'a mut im::Vector<T>, range: impl RangeBounds<usize>) -> Self {
+        let (start, end) = VecSlice::<T>::translate_range(range, 0, vector.len());
+        assert!(start <= end && end <= vector.len(), "range {start}..{end} out of range for vector of length {}", vector.len());
+        let mirror = vector.iter().skip(start).take(end - start).cloned().collect();
+        VectorMirror { vector, start, synced: end - start, mirror, modified: false }
+    }
+
+    /// Returns the vector as it would be after [`VectorMirror::sync`], without writing the mirror back.
+    ///
+    /// The returned vector shares its structure with the mirrored one outside of the mirrored range.
+    pub fn snapshot(&self) -> im::Vector<T> {
+        let mut snapshot = self.vector.clone();
+        if self.modified {
+            Self::write_back(&mut snapshot, self.start, self.synced, &self.mirror);
+        }
+        snapshot
+    }
+
+    /// Writes the mirror back into the vector, replacing the mirrored range.
+    pub fn sync(&mut self) {
+        if self.modified {
+            Self::write_back(self.vector, self.start, self.synced, &self.mirror);
+            self.synced = self.mirror.len();
+            self.modified = false;
+        }
+    }
+
+    fn write_back(vector: &mut im::Vector<T>, start: usize, len: usize, mirror: &[T]) {
+        let after = vector.split_off(start + len);
+        vector.truncate(start);
+        vector.extend(mirror.iter().cloned());
+        vector.append(after);
+    }
+}
+
+impl<T: Clone> Drop for VectorMirror<'_, T> {
+    fn drop(&mut self) {
+        self.sync();
+    }
+}
+
+impl<T: Clone> Sliceable<T> for VectorMirror<'_, T> {
+    fn len(&self) -> usize {
+        self.mirror.len()
+    }
+
+    fn insert(&mut self, index: usize, element: T) {
+        self.modified = true;
+        self.mirror.insert(index, element);
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        self.modified = true;
+        self.mirror.remove(index)
+    }
+
+    fn as_slice(&self) -> &[T] {
+        &self.mirror
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self.modified = true;
+        &mut self.mirror
+    }
+
+    fn as_mut_range(&mut self, start: usize, end: usize) -> &mut [T] {
+        self.modified = true;
+        &mut self.mirror[start..end]
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.mirror.reserve(additional);
+    }
+
+    fn clear_range(&mut self, start: usize, end: usize) {
+        self.modified = true;
+        self.mirror.drain(start..end);
+    }
+
+    fn splice_range<I: IntoIterator<Item = T>>(&mut self, start: usize, end: usize, replace_with: I) -> usize {
+        self.modified = true;
+        self.mirror.splice_range(start, end, replace_with)
+    }
+}
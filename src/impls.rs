@@ -0,0 +1,312 @@
+//! [`Sliceable`] implementations for the collections of other crates, each one behind the feature with the crate's name.
+
+#[allow(unused_imports)]
+use crate::{CapacityError, Sliceable, TrySliceable};
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> Sliceable<A::Item> for smallvec::SmallVec<A> {
+    fn len(&self) -> usize {
+        smallvec::SmallVec::len(self)
+    }
+
+    fn insert(&mut self, index: usize, element: A::Item) {
+        smallvec::SmallVec::insert(self, index, element);
+    }
+
+    fn remove(&mut self, index: usize) -> A::Item {
+        smallvec::SmallVec::remove(self, index)
+    }
+
+    fn as_slice(&self) -> &[A::Item] {
+        self
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [A::Item] {
+        self
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        smallvec::SmallVec::reserve(self, additional);
+    }
+
+    fn clear_range(&mut self, start: usize, end: usize) {
+        self.drain(start..end);
+    }
+
+    fn splice_range<I: IntoIterator<Item = A::Item>>(&mut self, start: usize, end: usize, replace_with: I) -> usize {
+        self.drain(start..end);
+        let len = smallvec::SmallVec::len(self);
+        self.insert_many(start, replace_with);
+        smallvec::SmallVec::len(self) - len
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> TrySliceable<A::Item> for smallvec::SmallVec<A> {
+    /// Never fails, as the vector spills to the heap when it runs out of inline capacity.
+    fn try_insert(&mut self, index: usize, element: A::Item) -> Result<(), CapacityError<A::Item>> {
+        smallvec::SmallVec::insert(self, index, element);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<T, const CAP: usize> Sliceable<T> for arrayvec::ArrayVec<T, CAP> {
+    fn len(&self) -> usize {
+        arrayvec::ArrayVec::len(self)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the vector is full, use [`TrySliceable::try_insert`] to handle it instead.
+    fn insert(&mut self, index: usize, element: T) {
+        arrayvec::ArrayVec::insert(self, index, element);
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        arrayvec::ArrayVec::remove(self, index)
+    }
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        Some(CAP)
+    }
+
+    fn clear_range(&mut self, start: usize, end: usize) {
+        self.drain(start..end);
+    }
+
+    fn splice_range<I: IntoIterator<Item = T>>(&mut self, start: usize, end: usize, replace_with: I) -> usize {
+        self.drain(start..end);
+        let tail: arrayvec::ArrayVec<T, CAP> = self.drain(start..).collect();
+        self.extend(replace_with);
+        let inserted = arrayvec::ArrayVec::len(self) - start;
+        self.extend(tail);
+        inserted
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<T, const CAP: usize> TrySliceable<T> for arrayvec::ArrayVec<T, CAP> {
+    fn try_insert(&mut self, index: usize, element: T) -> Result<(), CapacityError<T>> {
+        arrayvec::ArrayVec::try_insert(self, index, element).map_err(|err| CapacityError::new(err.element()))
+    }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> Sliceable<A::Item> for tinyvec::ArrayVec<A> {
+    fn len(&self) -> usize {
+        tinyvec::ArrayVec::len(self)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the vector is full, use [`TrySliceable::try_insert`] to handle it instead.
+    fn insert(&mut self, index: usize, element: A::Item) {
+        tinyvec::ArrayVec::insert(self, index, element);
+    }
+
+    fn remove(&mut self, index: usize) -> A::Item {
+        tinyvec::ArrayVec::remove(self, index)
+    }
+
+    fn as_slice(&self) -> &[A::Item] {
+        self
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [A::Item] {
+        self
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        Some(A::CAPACITY)
+    }
+
+    fn clear_range(&mut self, start: usize, end: usize) {
+        self.drain(start..end);
+    }
+
+    fn splice_range<I: IntoIterator<Item = A::Item>>(&mut self, start: usize, end: usize, replace_with: I) -> usize {
+        self.drain(start..end);
+        let tail = self.split_off(start);
+        self.extend(replace_with);
+        let inserted = tinyvec::ArrayVec::len(self) - start;
+        self.extend(tail);
+        inserted
+    }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> TrySliceable<A::Item> for tinyvec::ArrayVec<A> {
+    fn try_insert(&mut self, index: usize, element: A::Item) -> Result<(), CapacityError<A::Item>> {
+        match tinyvec::ArrayVec::try_insert(self, index, element) {
+            Some(element) => Err(CapacityError::new(element)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> Sliceable<A::Item> for tinyvec::TinyVec<A> {
+    fn len(&self) -> usize {
+        tinyvec::TinyVec::len(self)
+    }
+
+    fn insert(&mut self, index: usize, element: A::Item) {
+        tinyvec::TinyVec::insert(self, index, element);
+    }
+
+    fn remove(&mut self, index: usize) -> A::Item {
+        tinyvec::TinyVec::remove(self, index)
+    }
+
+    fn as_slice(&self) -> &[A::Item] {
+        self
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [A::Item] {
+        self
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        tinyvec::TinyVec::reserve(self, additional);
+    }
+
+    fn clear_range(&mut self, start: usize, end: usize) {
+        self.drain(start..end);
+    }
+
+    fn splice_range<I: IntoIterator<Item = A::Item>>(&mut self, start: usize, end: usize, replace_with: I) -> usize {
+        self.drain(start..end);
+        let tail = self.split_off(start);
+        self.extend(replace_with);
+        let inserted = tinyvec::TinyVec::len(self) - start;
+        self.extend(tail);
+        inserted
+    }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> TrySliceable<A::Item> for tinyvec::TinyVec<A> {
+    /// Never fails, as the vector moves to the heap when it runs out of inline capacity.
+    fn try_insert(&mut self, index: usize, element: A::Item) -> Result<(), CapacityError<A::Item>> {
+        tinyvec::TinyVec::insert(self, index, element);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<T, const N: usize> Sliceable<T> for heapless::Vec<T, N> {
+    fn len(&self) -> usize {
+        heapless::Vec::as_slice(self).len()
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the vector is full, use [`TrySliceable::try_insert`] to handle it instead.
+    fn insert(&mut self, index: usize, element: T) {
+        if heapless::Vec::insert(self, index, element).is_err() {
+            panic!("insertion into a full heapless::Vec (capacity is {N})");
+        }
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        heapless::Vec::remove(self, index)
+    }
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        Some(N)
+    }
+
+    fn clear_range(&mut self, start: usize, end: usize) {
+        let len = heapless::Vec::as_slice(self).len();
+        self[start..].rotate_left(end - start);
+        self.truncate(len - (end - start));
+    }
+
+    fn splice_range<I: IntoIterator<Item = T>>(&mut self, start: usize, end: usize, replace_with: I) -> usize {
+        self.clear_range(start, end);
+        let len = heapless::Vec::as_slice(self).len();
+        for element in replace_with {
+            if self.push(element).is_err() {
+                panic!("insertion into a full heapless::Vec (capacity is {N})");
+            }
+        }
+        let inserted = heapless::Vec::as_slice(self).len() - len;
+        self[start..].rotate_right(inserted);
+        inserted
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<T, const N: usize> TrySliceable<T> for heapless::Vec<T, N> {
+    fn try_insert(&mut self, index: usize, element: T) -> Result<(), CapacityError<T>> {
+        heapless::Vec::insert(self, index, element).map_err(CapacityError::new)
+    }
+}
+
+/// `BytesMut` has no insertion or removal in the middle, so they are done by rotating the bytes after `index`.
+#[cfg(feature = "bytes")]
+impl Sliceable<u8> for bytes::BytesMut {
+    fn len(&self) -> usize {
+        bytes::BytesMut::len(self)
+    }
+
+    fn insert(&mut self, index: usize, element: u8) {
+        let len = bytes::BytesMut::len(self);
+        assert!(index <= len, "insertion index (is {index}) should be <= len (is {len})");
+        self.extend_from_slice(&[element]);
+        self[index..].rotate_right(1);
+    }
+
+    fn remove(&mut self, index: usize) -> u8 {
+        let len = bytes::BytesMut::len(self);
+        assert!(index < len, "removal index (is {index}) should be < len (is {len})");
+        let element = self[index];
+        self[index..].rotate_left(1);
+        self.truncate(len - 1);
+        element
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        self
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        self
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        bytes::BytesMut::reserve(self, additional);
+    }
+
+    fn clear_range(&mut self, start: usize, end: usize) {
+        let len = bytes::BytesMut::len(self);
+        self[start..].rotate_left(end - start);
+        self.truncate(len - (end - start));
+    }
+
+    fn splice_range<I: IntoIterator<Item = u8>>(&mut self, start: usize, end: usize, replace_with: I) -> usize {
+        self.clear_range(start, end);
+        let len = bytes::BytesMut::len(self);
+        self.extend(replace_with);
+        let inserted = bytes::BytesMut::len(self) - len;
+        self[start..].rotate_right(inserted);
+        inserted
+    }
+}
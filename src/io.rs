@@ -0,0 +1,76 @@
+use std::io;
+
+use crate::{Sliceable, VecSlice};
+
+/// Appends the written bytes to the back of the slice, growing the underlying buffer in place.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use vecslice::Slice;
+///
+/// let mut buffer = b"HEAD|TAIL".to_vec();
+/// let mut slice = buffer.vecslice(5..5);
+/// write!(slice, "{}+{}|", 1, 2).unwrap();
+/// slice.write_all(b"body|").unwrap();
+/// assert_eq!(buffer, b"HEAD|1+2|body|TAIL");
+/// ```
+impl<S: Sliceable<u8>> io::Write for VecSlice<'_, u8, S> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.end += self.original.splice_range(self.end, self.end, buf.iter().copied());
+        Ok(buf.len())
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.write(buf).map(|_| ())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Reads bytes from the front of the slice, removing them from the underlying buffer.
+///
+/// The remaining bytes are shifted once per read, so prefer reading in large chunks,
+/// or through [`BufRead`](io::BufRead), which is also implemented.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{BufRead, Read};
+/// use vecslice::Slice;
+///
+/// let mut buffer = b"[GET /\nHost: x\n]".to_vec();
+/// let mut slice = buffer.vecslice(1..15);
+///
+/// let mut line = String::new();
+/// slice.read_line(&mut line).unwrap();
+/// assert_eq!(line, "GET /\n");
+///
+/// let mut header = [0; 4];
+/// slice.read_exact(&mut header).unwrap();
+/// assert_eq!(&header, b"Host");
+/// assert_eq!(buffer, b"[: x\n]");
+/// ```
+impl<S: Sliceable<u8>> io::Read for VecSlice<'_, u8, S> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let amount = buf.len().min(self.len());
+        buf[..amount].copy_from_slice(&self.as_slice()[..amount]);
+        io::BufRead::consume(self, amount);
+        Ok(amount)
+    }
+}
+
+impl<S: Sliceable<u8>> io::BufRead for VecSlice<'_, u8, S> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(self.as_slice())
+    }
+
+    fn consume(&mut self, amount: usize) {
+        let amount = amount.min(self.len());
+        self.original.clear_range(self.start, self.start + amount);
+        self.end -= amount;
+    }
+}
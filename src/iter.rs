@@ -1,6 +1,9 @@
-use crate::VecSlice;
+use std::collections::HashMap;
+use std::hash::Hash;
 
-impl<'a, T> VecSlice<'a, T> {
+use crate::{Drain, Sliceable, VecSlice};
+
+impl<'a, T, S: Sliceable<T>> VecSlice<'a, T, S> {
     /// Returns an iterator over the slice.
     ///
     /// The iterator yields all items from start to end.
@@ -61,37 +64,677 @@ impl<'a, T> VecSlice<'a, T> {
     pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
         self.into_iter()
     }
+
+    /// Returns an iterator over the slice that also yields the index of every element in the underlying vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec!['a', 'b', 'c', 'd'];
+    /// let slice = vec.vecslice(2..);
+    ///
+    /// let mut iterator = slice.iter_absolute();
+    /// assert_eq!(iterator.next(), Some((2, &'c')));
+    /// assert_eq!(iterator.next(), Some((3, &'d')));
+    /// assert_eq!(iterator.next(), None);
+    /// ```
+    pub fn iter_absolute(&self) -> core::iter::Zip<core::ops::Range<usize>, std::slice::Iter<'_, T>> {
+        (self.start..self.end).zip(self.iter())
+    }
+
+    /// Returns an iterator over `chunk_size` elements of the slice at a time, starting at the beginning of the slice.
+    ///
+    /// The chunks are slices and do not overlap. If `chunk_size` does not divide the length of the slice,
+    /// then the last chunk will not have length `chunk_size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5, 6];
+    /// let slice = vec.vecslice(1..6);
+    ///
+    /// let mut iter = slice.chunks(2);
+    /// assert_eq!(iter.next(), Some(&[1, 2][..]));
+    /// assert_eq!(iter.next(), Some(&[3, 4][..]));
+    /// assert_eq!(iter.next(), Some(&[5][..]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn chunks(&self, chunk_size: usize) -> std::slice::Chunks<'_, T> {
+        self.as_slice().chunks(chunk_size)
+    }
+
+    /// Returns an iterator over `chunk_size` elements of the slice at a time, starting at the beginning of the slice.
+    ///
+    /// The chunks are mutable slices and do not overlap. If `chunk_size` does not divide the length of the slice,
+    /// then the last chunk will not have length `chunk_size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 0, 0, 0, 0, 0];
+    /// let mut slice = vec.vecslice(1..);
+    ///
+    /// for (i, chunk) in slice.chunks_mut(2).enumerate() {
+    ///     chunk.fill(i + 1);
+    /// }
+    /// assert_eq!(vec, [0, 1, 1, 2, 2, 3]);
+    /// ```
+    pub fn chunks_mut(&mut self, chunk_size: usize) -> std::slice::ChunksMut<'_, T> {
+        self.as_mut_slice().chunks_mut(chunk_size)
+    }
+
+    /// Returns an iterator over `chunk_size` elements of the slice at a time, starting at the beginning of the slice.
+    ///
+    /// The chunks are slices and do not overlap. If `chunk_size` does not divide the length of the slice,
+    /// then the last up to `chunk_size-1` elements will be omitted and can be retrieved from the `remainder` function of the iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5, 6];
+    /// let slice = vec.vecslice(1..6);
+    ///
+    /// let mut iter = slice.chunks_exact(2);
+    /// assert_eq!(iter.next(), Some(&[1, 2][..]));
+    /// assert_eq!(iter.next(), Some(&[3, 4][..]));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.remainder(), &[5]);
+    /// ```
+    pub fn chunks_exact(&self, chunk_size: usize) -> std::slice::ChunksExact<'_, T> {
+        self.as_slice().chunks_exact(chunk_size)
+    }
+
+    /// Returns an iterator over `chunk_size` elements of the slice at a time, starting at the end of the slice.
+    ///
+    /// The chunks are slices and do not overlap. If `chunk_size` does not divide the length of the slice,
+    /// then the last chunk will not have length `chunk_size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5, 6];
+    /// let slice = vec.vecslice(1..6);
+    ///
+    /// let mut iter = slice.rchunks(2);
+    /// assert_eq!(iter.next(), Some(&[4, 5][..]));
+    /// assert_eq!(iter.next(), Some(&[2, 3][..]));
+    /// assert_eq!(iter.next(), Some(&[1][..]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn rchunks(&self, chunk_size: usize) -> std::slice::RChunks<'_, T> {
+        self.as_slice().rchunks(chunk_size)
+    }
+
+    /// Splits the slice into a slice of `N`-element arrays, starting at the beginning of the slice,
+    /// and a remainder slice with length strictly less than `N`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5, 6];
+    /// let slice = vec.vecslice(1..6);
+    ///
+    /// let (chunks, remainder) = slice.as_chunks::<2>();
+    /// assert_eq!(chunks, &[[1, 2], [3, 4]]);
+    /// assert_eq!(remainder, &[5]);
+    /// ```
+    pub fn as_chunks<const N: usize>(&self) -> (&[[T; N]], &[T]) {
+        self.as_slice().as_chunks()
+    }
+
+    /// Returns an iterator over `N` elements of the slice at a time, starting at the beginning of the slice.
+    ///
+    /// The chunks are arrays and do not overlap. If `N` does not divide the length of the slice,
+    /// then the last up to `N-1` elements will be omitted, see [`VecSlice::as_chunks`] to retrieve them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5, 6];
+    /// let slice = vec.vecslice(1..6);
+    ///
+    /// let mut iter = slice.array_chunks::<2>();
+    /// assert_eq!(iter.next(), Some(&[1, 2]));
+    /// assert_eq!(iter.next(), Some(&[3, 4]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn array_chunks<const N: usize>(&self) -> std::slice::Iter<'_, [T; N]> {
+        self.as_chunks().0.iter()
+    }
+
+    /// Returns an iterator over all contiguous windows of length `size` of the slice.
+    ///
+    /// The windows overlap. If the slice is shorter than `size`, the iterator returns no values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4];
+    /// let slice = vec.vecslice(1..4);
+    ///
+    /// let mut iter = slice.windows(2);
+    /// assert_eq!(iter.next(), Some(&[1, 2][..]));
+    /// assert_eq!(iter.next(), Some(&[2, 3][..]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn windows(&self, size: usize) -> std::slice::Windows<'_, T> {
+        self.as_slice().windows(size)
+    }
+
+    /// Returns an iterator over the slice producing non-overlapping runs of elements
+    /// using the predicate to separate them.
+    ///
+    /// The predicate is called for every pair of consecutive elements, a new run starts between them if it returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![1, 1, 1, 3, 3, 2, 2, 2];
+    /// let slice = vec.vecslice(1..7);
+    ///
+    /// let mut iter = slice.chunk_by(|a, b| a == b);
+    /// assert_eq!(iter.next(), Some(&[1, 1][..]));
+    /// assert_eq!(iter.next(), Some(&[3, 3][..]));
+    /// assert_eq!(iter.next(), Some(&[2, 2][..]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn chunk_by<F: FnMut(&T, &T) -> bool>(&self, pred: F) -> std::slice::ChunkBy<'_, T, F> {
+        self.as_slice().chunk_by(pred)
+    }
+
+    /// Returns an iterator over the slice producing non-overlapping mutable runs of elements
+    /// using the predicate to separate them.
+    ///
+    /// The predicate is called for every pair of consecutive elements, a new run starts between them if it returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 7, 8, 4];
+    /// let mut slice = vec.vecslice(1..6);
+    ///
+    /// // Reverse every increasing run
+    /// for run in slice.chunk_by_mut(|a, b| a + 1 == *b) {
+    ///     run.reverse();
+    /// }
+    /// assert_eq!(vec, [0, 3, 2, 1, 8, 7, 4]);
+    /// ```
+    pub fn chunk_by_mut<F: FnMut(&T, &T) -> bool>(&mut self, pred: F) -> std::slice::ChunkByMut<'_, T, F> {
+        self.as_mut_slice().chunk_by_mut(pred)
+    }
+
+    /// Returns an iterator over subslices separated by elements that match `pred`.
+    ///
+    /// The matched element is not contained in the subslices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = b"GET /index.html HTTP/1.1".to_vec();
+    /// let slice = vec.vecslice(4..);
+    ///
+    /// let mut iter = slice.split(|&b| b == b' ');
+    /// assert_eq!(iter.next(), Some(&b"/index.html"[..]));
+    /// assert_eq!(iter.next(), Some(&b"HTTP/1.1"[..]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn split<F: FnMut(&T) -> bool>(&self, pred: F) -> std::slice::Split<'_, T, F> {
+        self.as_slice().split(pred)
+    }
+
+    /// Returns an iterator over mutable subslices separated by elements that match `pred`.
+    ///
+    /// The matched element is not contained in the subslices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![10, 40, 30, 20, 60, 50];
+    /// let mut slice = vec.vecslice(1..);
+    ///
+    /// for group in slice.split_mut(|&x| x % 3 == 0) {
+    ///     group.fill(1);
+    /// }
+    /// assert_eq!(vec, [10, 1, 30, 1, 60, 1]);
+    /// ```
+    pub fn split_mut<F: FnMut(&T) -> bool>(&mut self, pred: F) -> std::slice::SplitMut<'_, T, F> {
+        self.as_mut_slice().split_mut(pred)
+    }
+
+    /// Returns an iterator over subslices separated by elements that match `pred`, limited to returning at most `n` items.
+    ///
+    /// The matched element is not contained in the subslices.
+    /// The last element returned, if any, will contain the remainder of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 10, 40, 30, 20, 60, 50];
+    /// let slice = vec.vecslice(1..);
+    ///
+    /// let mut iter = slice.splitn(2, |&x| x % 3 == 0);
+    /// assert_eq!(iter.next(), Some(&[10, 40][..]));
+    /// assert_eq!(iter.next(), Some(&[20, 60, 50][..]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn splitn<F: FnMut(&T) -> bool>(&self, n: usize, pred: F) -> std::slice::SplitN<'_, T, F> {
+        self.as_slice().splitn(n, pred)
+    }
+
+    /// Returns an iterator over subslices separated by elements that match `pred`,
+    /// starting at the end of the slice and working backwards.
+    ///
+    /// The matched element is not contained in the subslices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![11, 22, 33, 0, 44, 55];
+    /// let slice = vec.vecslice(1..);
+    ///
+    /// let mut iter = slice.rsplit(|&x| x == 0);
+    /// assert_eq!(iter.next(), Some(&[44, 55][..]));
+    /// assert_eq!(iter.next(), Some(&[22, 33][..]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn rsplit<F: FnMut(&T) -> bool>(&self, pred: F) -> std::slice::RSplit<'_, T, F> {
+        self.as_slice().rsplit(pred)
+    }
+
+    /// Groups references to the elements of the slice by the key returned by `key`.
+    ///
+    /// Unlike grouping consecutive runs, every element with the same key ends up in the same group,
+    /// in the order they appear in the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5, 6, 7];
+    /// let slice = vec.vecslice(1..7);
+    ///
+    /// let groups = slice.group_by_key(|x| x % 3);
+    /// assert_eq!(groups.len(), 3);
+    /// assert_eq!(groups[&0], [&3, &6]);
+    /// assert_eq!(groups[&1], [&1, &4]);
+    /// assert_eq!(groups[&2], [&2, &5]);
+    /// ```
+    pub fn group_by_key<K: Eq + Hash, F: FnMut(&T) -> K>(&self, mut key: F) -> HashMap<K, Vec<&T>> {
+        let mut groups: HashMap<K, Vec<&T>> = HashMap::new();
+        for elem in self {
+            groups.entry(key(elem)).or_default().push(elem);
+        }
+        groups
+    }
+
+    /// Returns the range of the longest strictly increasing run of consecutive elements in the slice.
+    ///
+    /// The range is relative to the slice. If several runs have the same length, the first one is returned.
+    /// An empty slice returns `0..0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![9, 1, 2, 0, 3, 4, 5, 1, 9];
+    /// let slice = vec.vecslice(1..8);
+    /// assert_eq!(slice, [1, 2, 0, 3, 4, 5, 1]);
+    /// assert_eq!(slice.longest_increasing_run(), 2..6);
+    /// ```
+    pub fn longest_increasing_run(&self) -> core::ops::Range<usize> where T: PartialOrd {
+        let slice = self.as_slice();
+        let mut longest = 0..slice.len().min(1);
+        let mut run_start = 0;
+        for i in 1..slice.len() {
+            if slice[i - 1] >= slice[i] {
+                run_start = i;
+            }
+            if i + 1 - run_start > longest.len() {
+                longest = run_start..i + 1;
+            }
+        }
+        longest
+    }
+
+    /// Returns the indices of every element of the slice that satisfies `pred`.
+    ///
+    /// The indices are relative to the slice, so they can be passed directly to [`VecSlice::remove`] or [`VecSlice::drain`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5, 6];
+    /// let slice = vec.vecslice(3..);
+    /// assert_eq!(slice, [3, 4, 5, 6]);
+    /// assert_eq!(slice.positions(|x| x % 2 == 0), [1, 3]);
+    /// ```
+    pub fn positions<P: FnMut(&T) -> bool>(&self, mut pred: P) -> Vec<usize> {
+        self.iter().enumerate().filter(|(_, elem)| pred(elem)).map(|(i, _)| i).collect()
+    }
+
+    /// Folds every element of the slice into an accumulator, only visiting the elements inside the slice.
+    ///
+    /// Equivalent to `slice.iter().fold(init, f)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0xff, 0b0011, 0b0101, 0b1001, 0xff];
+    /// let slice = vec.vecslice(1..4);
+    ///
+    /// let checksum = slice.fold(0, |acc, x| acc ^ x);
+    /// assert_eq!(checksum, 0b1111);
+    /// ```
+    pub fn fold<B, F: FnMut(B, &T) -> B>(&self, init: B, f: F) -> B {
+        self.iter().fold(init, f)
+    }
+
+    /// Returns `true` if every element of the slice satisfies `pred`.
+    ///
+    /// Only the elements inside the slice are checked, an empty slice always returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![1, 2, 4, 6, 7];
+    /// assert!(vec.vecslice(1..4).all(|x| x % 2 == 0));
+    /// assert!(!vec.vecslice(1..).all(|x| x % 2 == 0));
+    /// assert!(vec.vecslice(0..0).all(|x| x % 2 == 0));
+    /// ```
+    pub fn all<P: FnMut(&T) -> bool>(&self, pred: P) -> bool {
+        self.iter().all(pred)
+    }
+
+    /// Returns `true` if any element of the slice satisfies `pred`.
+    ///
+    /// Only the elements inside the slice are checked, an empty slice always returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![1, 2, 4, 6, 7];
+    /// assert!(vec.vecslice(3..).any(|x| x % 2 == 1));
+    /// assert!(!vec.vecslice(1..4).any(|x| x % 2 == 1));
+    /// assert!(!vec.vecslice(0..0).any(|x| x % 2 == 1));
+    /// ```
+    pub fn any<P: FnMut(&T) -> bool>(&self, pred: P) -> bool {
+        self.iter().any(pred)
+    }
+
+    /// Returns `true` if the slice has the same elements as `iter`, without collecting it.
+    ///
+    /// Stops at the first mismatch, or as soon as one of them runs out of elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![9, 0, 1, 2, 9];
+    /// let slice = vec.vecslice(1..4);
+    /// assert!(slice.eq_iter(0..3));
+    /// assert!(!slice.eq_iter(0..4));
+    /// assert!(slice.eq_iter([0, 2, 4].into_iter().map(|x| x / 2)));
+    /// assert!(!slice.eq_iter([0, 2, 4].into_iter()));
+    /// ```
+    pub fn eq_iter<I: IntoIterator<Item = T>>(&self, iter: I) -> bool where T: PartialEq {
+        let mut iter = iter.into_iter();
+        for elem in self {
+            match iter.next() {
+                Some(other) if *elem == other => {}
+                _ => return false,
+            }
+        }
+        iter.next().is_none()
+    }
+
+    /// [Lexicographically](Ord#lexicographical-comparison) compares the slice with the elements of `iter`, without collecting it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut vec = vec![9, 0, 1, 2, 9];
+    /// let slice = vec.vecslice(1..4);
+    /// assert_eq!(slice.cmp_iter(0..3), Ordering::Equal);
+    /// assert_eq!(slice.cmp_iter(0..4), Ordering::Less);
+    /// assert_eq!(slice.cmp_iter(0..2), Ordering::Greater);
+    /// assert_eq!(slice.cmp_iter((0..3).map(|x| x * 2)), Ordering::Less);
+    /// ```
+    pub fn cmp_iter<I: IntoIterator<Item = T>>(&self, iter: I) -> core::cmp::Ordering where T: Ord {
+        let mut iter = iter.into_iter();
+        for elem in self {
+            let Some(other) = iter.next() else {
+                return core::cmp::Ordering::Greater;
+            };
+            match elem.cmp(&other) {
+                core::cmp::Ordering::Equal => {}
+                ordering => return ordering,
+            }
+        }
+        if iter.next().is_some() {
+            core::cmp::Ordering::Less
+        } else {
+            core::cmp::Ordering::Equal
+        }
+    }
+
+    /// Returns references to the minimum and maximum elements of the slice in a single pass, or [`None`] if it is empty.
+    ///
+    /// Elements are compared in pairs, so it takes about `1.5 * len` comparisons instead of the `2 * len` of separate `min` and `max` calls.
+    /// As with [`Iterator::min`] and [`Iterator::max`], the first minimum and the last maximum are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![-10, 3, 1, 4, 1, 5, 10];
+    /// let slice = vec.vecslice(1..6);
+    /// assert_eq!(slice.min_max(), Some((&1, &5)));
+    ///
+    /// let slice = vec.vecslice(3..4);
+    /// assert_eq!(slice.min_max(), Some((&4, &4)));
+    ///
+    /// let slice = vec.vecslice(3..3);
+    /// assert_eq!(slice.min_max(), None);
+    /// ```
+    pub fn min_max(&self) -> Option<(&T, &T)> where T: Ord {
+        let mut iter = self.iter();
+        let first = iter.next()?;
+        let (mut min, mut max) = (first, first);
+        while let Some(a) = iter.next() {
+            let (small, large) = match iter.next() {
+                Some(b) if b < a => (b, a),
+                Some(b) => (a, b),
+                None => (a, a),
+            };
+            if small < min {
+                min = small;
+            }
+            if large >= max {
+                max = large;
+            }
+        }
+        Some((min, max))
+    }
+
+    /// Returns the maximum of every window of length `window` in the slice.
+    ///
+    /// Runs in *O*(*n*) regardless of the window size.
+    /// If `window` is `0` or greater than the length of the slice, the result is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![9, 1, 3, 2, 5, 4, 9];
+    /// let slice = vec.vecslice(1..6);
+    /// assert_eq!(slice.sliding_max(2), [3, 3, 5, 5]);
+    /// assert_eq!(slice.sliding_max(5), [5]);
+    /// assert_eq!(slice.sliding_max(6), []);
+    /// ```
+    pub fn sliding_max(&self, window: usize) -> Vec<T> where T: Ord + Copy {
+        self.sliding_by(window, |new, old| new >= old)
+    }
+
+    /// Returns the minimum of every window of length `window` in the slice.
+    ///
+    /// Runs in *O*(*n*) regardless of the window size.
+    /// If `window` is `0` or greater than the length of the slice, the result is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 3, 2, 5, 4, 0];
+    /// let slice = vec.vecslice(1..6);
+    /// assert_eq!(slice.sliding_min(2), [1, 2, 2, 4]);
+    /// assert_eq!(slice.sliding_min(5), [1]);
+    /// assert_eq!(slice.sliding_min(0), []);
+    /// ```
+    pub fn sliding_min(&self, window: usize) -> Vec<T> where T: Ord + Copy {
+        self.sliding_by(window, |new, old| new <= old)
+    }
+
+    /// Monotonic deque over the indices of the slice, `replaces(new, old)` returns whether `new` makes `old` useless as a window result.
+    fn sliding_by(&self, window: usize, replaces: impl Fn(&T, &T) -> bool) -> Vec<T> where T: Copy {
+        let slice = self.as_slice();
+        if window == 0 || window > slice.len() {
+            return Vec::new();
+        }
+
+        let mut result = Vec::with_capacity(slice.len() - window + 1);
+        let mut deque = std::collections::VecDeque::with_capacity(window);
+        for (i, elem) in slice.iter().enumerate() {
+            while deque.back().is_some_and(|&j| replaces(elem, &slice[j])) {
+                deque.pop_back();
+            }
+            deque.push_back(i);
+            if deque[0] + window <= i {
+                deque.pop_front();
+            }
+            if i + 1 >= window {
+                result.push(slice[deque[0]]);
+            }
+        }
+        result
+    }
 }
 
-impl<'a, T> IntoIterator for VecSlice<'a, T> {
-    type Item = &'a T;
-    type IntoIter = std::slice::Iter<'a, T>;
+/// Removes the elements of the slice from the vector, yielding them by value.
+///
+/// # Examples
+///
+/// ```
+/// use vecslice::Slice;
+///
+/// let mut vec = vec![String::from("a"), String::from("b"), String::from("c")];
+/// let slice = vec.vecslice(1..);
+///
+/// let owned: Vec<String> = slice.into_iter().collect();
+/// assert_eq!(owned, ["b", "c"]);
+/// assert_eq!(vec, ["a"]);
+/// ```
+impl<'a, T, S: Sliceable<T>> IntoIterator for VecSlice<'a, T, S> {
+    type Item = T;
+    type IntoIter = Drain<'a, T, S>;
     
     fn into_iter(self) -> Self::IntoIter {
-        self.vec[self.start..self.end].iter()
+        Drain::new(self.original, self.start, self.end, None)
     }
 }
 
-impl<'a, T> IntoIterator for &'a VecSlice<'_, T> {
+impl<'a, T, S: Sliceable<T>> IntoIterator for &'a VecSlice<'_, T, S> {
     type Item = &'a T;
     type IntoIter = std::slice::Iter<'a, T>;
     
     fn into_iter(self) -> Self::IntoIter {
-        self.vec[self.start..self.end].iter()
+        self.as_slice().iter()
     }
 }
 
-impl<'a, T> IntoIterator for &'a mut VecSlice<'_, T> {
+impl<'a, T, S: Sliceable<T>> IntoIterator for &'a mut VecSlice<'_, T, S> {
     type Item = &'a mut T;
     type IntoIter = std::slice::IterMut<'a, T>;
     
     fn into_iter(self) -> Self::IntoIter {
-        self.vec[self.start..self.end].iter_mut()
+        self.as_mut_slice().iter_mut()
     }
 }
 
-impl<'a, T> From<&'a mut Vec<T>> for VecSlice<'a, T> {
-    fn from(vec: &'a mut Vec<T>) -> Self {
-        Self::new(.., vec)
+impl<'a, T, S: Sliceable<T>> From<&'a mut S> for VecSlice<'a, T, S> {
+    fn from(original: &'a mut S) -> Self {
+        Self::new(.., original)
     }
 }
\ No newline at end of file
@@ -0,0 +1,224 @@
+use core::ops::RangeBounds;
+
+use crate::{Sliceable, VecSlice};
+
+/// Edit recorded by a [`Journaled`] slice, with the elements needed to undo and redo it.
+enum Edit<T> {
+    Insert { index: usize, elements: Vec<T> },
+    Remove { index: usize, elements: Vec<T> },
+}
+
+/// Wrapper around a [`VecSlice`] that records every edit, so it can be undone and redone.
+///
+/// Only the edits made through the wrapper are recorded, the slice can't be accessed mutably in any other way
+/// until it is recovered with [`Journaled::into_inner`].
+/// Making a new edit after undoing discards the edits that could be redone.
+///
+/// # Examples
+///
+/// ```
+/// use vecslice::{Journaled, Slice};
+///
+/// let mut vec = vec![0, 1, 2, 3];
+/// let mut text = Journaled::new(vec.vecslice(1..3));
+///
+/// text.push_back(4);
+/// text.remove(0);
+/// assert_eq!(text.as_slice(), [2, 4]);
+///
+/// assert!(text.undo());
+/// assert_eq!(text.as_slice(), [1, 2, 4]);
+/// assert!(text.undo());
+/// assert!(!text.undo());
+/// assert!(text.redo());
+/// assert_eq!(text.as_slice(), [1, 2, 4]);
+///
+/// text.into_inner();
+/// assert_eq!(vec, [0, 1, 2, 4, 3]);
+/// ```
+pub struct Journaled<'a, T, S: Sliceable<T> = Vec<T>> {
+    slice: VecSlice<'a, T, S>,
+    undo: Vec<Edit<T>>,
+    redo: Vec<Edit<T>>,
+}
+
+impl<'a, T: Clone, S: Sliceable<T>> Journaled<'a, T, S> {
+    /// Wraps `slice`, starting with an empty history.
+    pub fn new(slice: VecSlice<'a, T, S>) -> Self {
+        Journaled { slice, undo: Vec::new(), redo: Vec::new() }
+    }
+
+    /// Returns the wrapped slice, discarding the history.
+    pub fn into_inner(self) -> VecSlice<'a, T, S> {
+        self.slice
+    }
+
+    /// Returns the number of elements in the slice.
+    pub fn len(&self) -> usize {
+        self.slice.len()
+    }
+
+    /// Returns `true` if the slice has a length of 0.
+    pub fn is_empty(&self) -> bool {
+        self.slice.is_empty()
+    }
+
+    /// Extracts a slice containing the elements of the wrapped slice.
+    pub fn as_slice(&self) -> &[T] {
+        self.slice.as_slice()
+    }
+
+    /// Returns `true` if there is an edit to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns `true` if there is an undone edit to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Forgets all the recorded edits, keeping the current contents.
+    pub fn clear_history(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+
+    /// Undoes the last edit, returning `false` if there was nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        let Some(edit) = self.undo.pop() else {
+            return false;
+        };
+        self.revert(&edit);
+        self.redo.push(edit);
+        true
+    }
+
+    /// Redoes the last undone edit, returning `false` if there was nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        let Some(edit) = self.redo.pop() else {
+            return false;
+        };
+        self.apply(&edit);
+        self.undo.push(edit);
+        true
+    }
+
+    fn apply(&mut self, edit: &Edit<T>) {
+        match edit {
+            Edit::Insert { index, elements } => self.slice.insert_slice(*index, elements),
+            Edit::Remove { index, elements } => self.remove_range(*index, elements.len()),
+        }
+    }
+
+    fn revert(&mut self, edit: &Edit<T>) {
+        match edit {
+            Edit::Insert { index, elements } => self.remove_range(*index, elements.len()),
+            Edit::Remove { index, elements } => self.slice.insert_slice(*index, elements),
+        }
+    }
+
+    fn remove_range(&mut self, index: usize, len: usize) {
+        let start = self.slice.start + index;
+        self.slice.original.clear_range(start, start + len);
+        self.slice.end -= len;
+    }
+
+    fn record(&mut self, edit: Edit<T>) {
+        self.redo.clear();
+        self.undo.push(edit);
+    }
+
+    /// Appends an element to the back of the slice.
+    pub fn push_back(&mut self, element: T) {
+        self.insert(self.len(), element);
+    }
+
+    /// Appends an element to the front of the slice.
+    pub fn push_front(&mut self, element: T) {
+        self.insert(0, element);
+    }
+
+    /// Removes the last element of the slice and returns it, or [`None`] if it is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        (!self.is_empty()).then(|| self.remove(self.len() - 1))
+    }
+
+    /// Removes the first element of the slice and returns it, or [`None`] if it is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        (!self.is_empty()).then(|| self.remove(0))
+    }
+
+    /// Inserts an element at position `index` within the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, element: T) {
+        self.slice.insert(index, element.clone());
+        self.record(Edit::Insert { index, elements: vec![element] });
+    }
+
+    /// Inserts all the elements of `values` at position `index` within the slice, recorded as a single edit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert_slice(&mut self, index: usize, values: &[T]) {
+        self.slice.insert_slice(index, values);
+        self.record(Edit::Insert { index, elements: values.to_vec() });
+    }
+
+    /// Removes and returns the element at position `index` within the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        let element = self.slice.remove(index);
+        self.record(Edit::Remove { index, elements: vec![element.clone()] });
+        element
+    }
+
+    /// Removes the specified range from the slice, returning the removed elements, recorded as a single edit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::{Journaled, Slice};
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4];
+    /// let mut journaled = Journaled::new(vec.vecslice(..));
+    /// assert_eq!(journaled.drain(1..4), [1, 2, 3]);
+    /// assert_eq!(journaled.as_slice(), [0, 4]);
+    /// journaled.undo();
+    /// assert_eq!(journaled.as_slice(), [0, 1, 2, 3, 4]);
+    /// ```
+    pub fn drain(&mut self, range: impl RangeBounds<usize>) -> Vec<T> {
+        let (start, end) = VecSlice::<T, S>::translate_range(range, 0, self.len());
+        let elements: Vec<T> = self.slice.drain(start..end).collect();
+        self.record(Edit::Remove { index: start, elements: elements.clone() });
+        elements
+    }
+
+    /// Removes all the elements of the slice, recorded as a single edit.
+    pub fn clear(&mut self) {
+        self.drain(..);
+    }
+}
+
+impl<'a, T: Clone, S: Sliceable<T>> From<VecSlice<'a, T, S>> for Journaled<'a, T, S> {
+    fn from(slice: VecSlice<'a, T, S>) -> Self {
+        Self::new(slice)
+    }
+}
+
+impl<T: core::fmt::Debug, S: Sliceable<T>> core::fmt::Debug for Journaled<'_, T, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Journaled").field("slice", &self.slice.as_slice()).field("undo", &self.undo.len()).field("redo", &self.redo.len()).finish()
+    }
+}
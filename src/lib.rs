@@ -8,6 +8,34 @@
 //! 
 //! If you use [`VecSlice::new_at_tail`] to create a slice, the complexity of push operations on the new slice will be O(1).
 //! 
+//! # Optional features
+//!
+//! Each of these features implements [`Sliceable`] for the collections of the crate with the same name:
+//!
+//! - `arrayvec`: [`ArrayVec`](https://docs.rs/arrayvec/0.7/arrayvec/struct.ArrayVec.html), also implementing [`TrySliceable`].
+//! - `bytes`: [`BytesMut`](https://docs.rs/bytes/1/bytes/struct.BytesMut.html).
+//! - `heapless`: [`heapless::Vec`](https://docs.rs/heapless/0.8/heapless/struct.Vec.html), also implementing [`TrySliceable`].
+//! - `smallvec`: [`SmallVec`](https://docs.rs/smallvec/1/smallvec/struct.SmallVec.html).
+//! - `tinyvec`: [`TinyVec`](https://docs.rs/tinyvec/1/tinyvec/enum.TinyVec.html) and [`ArrayVec`](https://docs.rs/tinyvec/1/tinyvec/struct.ArrayVec.html), the latter also implementing [`TrySliceable`].
+//!
+//! The `arbitrary` feature adds the [`fuzz`] module, to drive differential fuzzing of a [`VecSlice`] against a [`Vec`].
+//!
+//! The `im` feature adds [`VectorMirror`], to edit a range of a persistent [`im::Vector`](https://docs.rs/im/15/im/struct.Vector.html) through a [`VecSlice`].
+//!
+//! The `bytemuck` feature adds [`VecSlice::cast_vecslice`] and [`VecSlice::try_cast_vecslice`], to reinterpret the elements of a slice as another [`Pod`](https://docs.rs/bytemuck/1/bytemuck/trait.Pod.html) type.
+//!
+//! The `proptest` feature adds the [`strategies`] module, to property-test [`Sliceable`] backends against a [`Vec`],
+//! and the [`fuzz`] module its operations come from.
+//!
+//! The `rand` feature adds [`VecSlice::shuffle`], [`VecSlice::partial_shuffle`], [`VecSlice::choose`] and [`VecSlice::choose_mut`].
+//!
+//! The `rayon` feature implements [`IntoParallelIterator`](https://docs.rs/rayon/1/rayon/iter/trait.IntoParallelIterator.html) for references to a [`VecSlice`],
+//! providing `par_iter` and `par_iter_mut` over its elements.
+//!
+//! The `serde` feature implements [`Serialize`](https://docs.rs/serde/1/serde/trait.Serialize.html) for [`VecSlice`] and adds [`VecSlice::deserialize_in_place`].
+//!
+//! The `allocator_api` feature requires a nightly compiler and implements [`Sliceable`] for `Vec<T, A>` with any [`Allocator`](std::alloc::Allocator).
+//!
 //! # Examples
 //!
 //! ```
@@ -44,13 +72,74 @@
 //! ```
 //! For more information, see the [`VecSlice`] struct documentation.
 
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+
+use core::marker::PhantomData;
 use core::ops::RangeBounds;
 
 mod iter;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impls;
+mod cell;
+mod impls;
 mod index;
+mod io;
+mod journaled;
+mod chunked_vec;
+mod cursor;
+mod dirty;
+mod drain;
+mod error;
+mod extract_if;
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+pub mod fuzz;
+mod gap_buffer;
+#[cfg(feature = "im")]
+mod im_impls;
+mod owned;
+mod reversed;
+#[cfg(feature = "rand")]
+mod rand_impls;
+#[cfg(feature = "rayon")]
+mod rayon_impls;
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod sliceable;
+mod slice2d;
+mod split;
+mod strided;
+mod tracked;
+mod transaction;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+mod str_slice;
+mod vecslice_ref;
+
+pub use cell::{CellSlice, VecSliceCell};
+pub use chunked_vec::ChunkedVec;
+pub use cursor::Cursor;
+pub use dirty::DirtyTracker;
+pub use drain::Drain;
+pub use error::{CapacityError, VecSliceError};
+pub use extract_if::ExtractIf;
+pub use gap_buffer::GapBuffer;
+#[cfg(feature = "im")]
+pub use im_impls::VectorMirror;
+pub use journaled::Journaled;
+pub use owned::VecSliceOwned;
+pub use reversed::Reversed;
+pub use sliceable::{Sliceable, TrySliceable};
+pub use slice2d::VecSlice2D;
+pub use split::{SplitAt, Splits};
+pub use strided::StridedSlice;
+pub use str_slice::StrSlice;
+pub use tracked::{Tracked, TrackedIndex};
+pub use vecslice_ref::VecSliceRef;
 
 /// Growable mutable reference on a [`Vec`].
 /// 
+/// Any other [`Sliceable`] collection can be used as the underlying buffer instead of a [`Vec`] through the `S` parameter.
+/// 
 /// Due to requiring a mutable reference to the underlying buffer, only one [`VecSlice`] can exist at a time, ensuring memory safety.
 /// 
 /// # Complexity
@@ -73,13 +162,17 @@ mod index;
 /// assert_eq!(slice, [1, 2, 3]);
 /// assert_eq!(vec, [1, 2, 3, 3]);
 /// ```
-#[derive(Eq, Ord)]
-pub struct VecSlice<'a, T> {
+pub struct VecSlice<'a, T, S: Sliceable<T> = Vec<T>> {
     start: usize,
     end: usize,
-    vec: &'a mut Vec<T>
+    original: &'a mut S,
+    /// First position the slice can grow over, the start of the slice it was lent from.
+    min_start: usize,
+    /// Number of elements at the end of the buffer the slice can't grow over, the ones after the slice it was lent from.
+    max_tail: usize,
+    marker: PhantomData<T>
 }
-impl<'a, T> VecSlice<'a, T> {
+impl<'a, T, S: Sliceable<T>> VecSlice<'a, T, S> {
     fn translate_range(range: impl RangeBounds<usize>, start: usize, end: usize) -> (usize, usize) {
         use core::ops::Bound::*;
         match (range.start_bound(), range.end_bound()) {
@@ -95,11 +188,81 @@ impl<'a, T> VecSlice<'a, T> {
         }
     }
 
-    pub fn new(range: impl RangeBounds<usize>, vec: &'a mut Vec<T>) -> VecSlice<'a, T> {
-        let (start, end) = VecSlice::<T>::translate_range(range, 0, vec.len());
-        VecSlice { start, end, vec }
+    /// Checks that `range` is valid for a slice of length `len`, returning its bounds.
+    fn try_translate_range(range: impl RangeBounds<usize>, len: usize) -> Result<(usize, usize), VecSliceError> {
+        use core::ops::Bound::*;
+        let start = match range.start_bound() {
+            Included(&s) => s,
+            Excluded(&s) => s.checked_add(1).ok_or(VecSliceError::StartOutOfBounds { start: s, len })?,
+            Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Included(&e) => e.checked_add(1).ok_or(VecSliceError::EndOutOfBounds { end: e, len })?,
+            Excluded(&e) => e,
+            Unbounded => len,
+        };
+        if start > len {
+            Err(VecSliceError::StartOutOfBounds { start, len })
+        } else if end > len {
+            Err(VecSliceError::EndOutOfBounds { end, len })
+        } else if start > end {
+            Err(VecSliceError::InvertedRange { start, end })
+        } else {
+            Ok((start, end))
+        }
+    }
+
+    pub fn new(range: impl RangeBounds<usize>, original: &'a mut S) -> VecSlice<'a, T, S> {
+        original.make_contiguous();
+        let (start, end) = Self::translate_range(range, 0, original.len());
+        VecSlice { start, end, original, min_start: 0, max_tail: 0, marker: PhantomData }
+    }
+
+    /// Creates a new [`VecSlice`] of `original` on the specified range, or returns an error if the range is out of bounds.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use vecslice::{VecSlice, VecSliceError};
+    /// 
+    /// let mut vec = vec![1, 2, 3];
+    /// assert_eq!(VecSlice::try_new(1.., &mut vec).unwrap(), [2, 3]);
+    /// assert_eq!(VecSlice::try_new(1..4, &mut vec).unwrap_err(), VecSliceError::EndOutOfBounds { end: 4, len: 3 });
+    /// assert_eq!(VecSlice::try_new(2..1, &mut vec).unwrap_err(), VecSliceError::InvertedRange { start: 2, end: 1 });
+    /// ```
+    pub fn try_new(range: impl RangeBounds<usize>, original: &'a mut S) -> Result<VecSlice<'a, T, S>, VecSliceError> {
+        original.make_contiguous();
+        let (start, end) = Self::try_translate_range(range, original.len())?;
+        Ok(VecSlice { start, end, original, min_start: 0, max_tail: 0, marker: PhantomData })
     }
     
+    /// Creates a [`VecSlice`] on `start..end` that is lent by another slice or wrapper which keeps track of its bounds,
+    /// so it can't grow or move past them.
+    pub(crate) fn lent(start: usize, end: usize, original: &'a mut S) -> VecSlice<'a, T, S> {
+        let limits = (start, original.len() - end);
+        VecSlice::with_limits(start, end, original, limits)
+    }
+
+    /// Creates a [`VecSlice`] on `start..end` that can grow from `min_start` up to `max_tail` elements before the end of the buffer.
+    pub(crate) fn with_limits(start: usize, end: usize, original: &'a mut S, (min_start, max_tail): (usize, usize)) -> VecSlice<'a, T, S> {
+        VecSlice { start, end, original, min_start, max_tail, marker: PhantomData }
+    }
+
+    /// Returns the limits the slice can grow to, to create another slice with them through [`VecSlice::with_limits`].
+    pub(crate) fn limits(&self) -> (usize, usize) {
+        (self.min_start, self.max_tail)
+    }
+
+    /// Number of elements before the slice that it can grow over.
+    fn room_before(&self) -> usize {
+        self.start - self.min_start
+    }
+
+    /// Number of elements after the slice that it can grow over.
+    fn room_after(&self) -> usize {
+        self.original.len() - self.max_tail - self.end
+    }
+
     /// Creates a new [`VecSlice`] at the tail of the current one.
     /// 
     /// The new slice will be empty, and newly added elements will be appended to the end of the [`VecSlice`].
@@ -121,465 +284,2984 @@ impl<'a, T> VecSlice<'a, T> {
     /// assert_eq!(slice, [1, 2, 3]);
     /// assert_eq!(vec, [1, 2, 3, 4]);
     /// ```
-    pub fn new_at_tail(&mut self) -> VecSlice<'_, T> {
-        VecSlice::new(self.end.., self.vec)
-    }
-    
-    pub fn len(&self) -> usize {
-        self.end - self.start
+    pub fn new_at_tail(&mut self) -> VecSlice<'_, T, S> {
+        VecSlice { start: self.end, end: self.end, original: self.original, min_start: self.end, max_tail: self.max_tail, marker: PhantomData }
     }
 
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
-    }
-    
-    /// Appends an element to the back of a collection.
-    /// 
-    /// If you'd like to push at the front of the collection, use [`VecSlice::push_front`] instead.
+    /// Narrows the slice to `range`, relative to the current slice, consuming it.
     ///
-    /// [`VecSlice::push_front`]: crate::VecSlice::push_front
+    /// Unlike creating a new [`VecSlice`] on top of this one, the result keeps borrowing the underlying buffer
+    /// for the original lifetime, so it can be returned from a function.
     ///
     /// # Panics
     ///
-    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    /// Panics if the range is out of bounds.
     ///
     /// # Examples
     ///
     /// ```
+    /// use vecslice::{Slice, VecSlice};
+    ///
+    /// fn body(vec: &mut Vec<u8>) -> VecSlice<'_, u8> {
+    ///     let frame = vec.vecslice(..);
+    ///     let len = frame.len();
+    ///     frame.reslice(1..len - 1)
+    /// }
+    ///
+    /// let mut vec = b"<hi>".to_vec();
+    /// body(&mut vec).push_back(b'!');
+    /// assert_eq!(vec, b"<hi!>");
+    /// ```
+    pub fn reslice(self, range: impl RangeBounds<usize>) -> VecSlice<'a, T, S> {
+        let (start, end) = Self::try_translate_range(range, self.len()).unwrap_or_else(|err| panic!("{err}"));
+        VecSlice { start: self.start + start, end: self.start + end, original: self.original, min_start: self.min_start, max_tail: self.max_tail, marker: PhantomData }
+    }
+
+    /// Consumes the slice, returning the mutable reference to the underlying buffer.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
     /// use vecslice::Slice;
     /// 
-    /// let mut vec = vec![0, 1, 2, 3];
-    /// let mut slice = vec.vecslice(1..=2);
-    /// assert_eq!(slice, [1, 2]);
+    /// let mut vec = vec![1, 2, 3];
+    /// let mut slice = vec.vecslice(1..);
     /// slice.push_back(4);
-    /// slice.push_back(5);
-    /// assert_eq!(slice, [1, 2, 4, 5]);
-    /// assert_eq!(vec, [0, 1, 2, 4, 5, 3]);
+    /// 
+    /// let original = slice.into_original();
+    /// original.push(5);
+    /// assert_eq!(vec, [1, 2, 3, 4, 5]);
     /// ```
-    pub fn push_back(&mut self, element: T) {
-        self.vec.insert(self.end, element);
-        self.end += 1;
+    pub fn into_original(self) -> &'a mut S {
+        self.original
     }
 
-    /// Appends an element to the front of a collection.
-    ///
+    /// Splits the slice into two adjacent halves at `mid`, consuming it.
+    /// 
+    /// The left half contains the elements in `[0, mid)` and the right half the ones in `[mid, len)`.
+    /// Both halves can grow and shrink independently, see [`SplitAt`] for more information.
+    /// 
     /// # Panics
-    ///
-    /// Panics if the new capacity exceeds `isize::MAX` bytes.
-    ///
+    /// 
+    /// Panics if `mid > len`.
+    /// 
     /// # Examples
-    ///
+    /// 
     /// ```
     /// use vecslice::Slice;
     /// 
-    /// let mut vec = vec![0, 1, 2, 3];
-    /// let mut slice = vec.vecslice(1..=2);
-    /// assert_eq!(slice, [1, 2]);
-    /// slice.push_front(4);
-    /// slice.push_front(5);
-    /// assert_eq!(slice, [5, 4, 1, 2]);
-    /// assert_eq!(vec, [0, 5, 4, 1, 2, 3]);
+    /// let mut vec = vec![1, 2, 3, 4];
+    /// let mut split = vec.vecslice(..).split_at(2);
+    /// split.left().insert(1, 5);
+    /// split.right().push_back(6);
+    /// assert_eq!(split.left(), [1, 5, 2]);
+    /// assert_eq!(split.right(), [3, 4, 6]);
+    /// assert_eq!(split.join(), [1, 5, 2, 3, 4, 6]);
     /// ```
-    pub fn push_front(&mut self, element: T) {
-        self.vec.insert(self.start, element);
-        self.end += 1;
+    pub fn split_at(self, mid: usize) -> SplitAt<'a, T, S> {
+        assert!(mid <= self.len());
+        let limits = self.limits();
+        SplitAt::new(self.original, self.start, self.start+mid, self.end, limits, None)
     }
-    
-    /// Inserts an element at position `index` within the slice, shifting all
-    /// elements after it to the right.
-    ///
+
+    /// Splits the slice into two adjacent halves at `mid`.
+    /// 
+    /// The left half contains the elements in `[0, mid)` and the right half the ones in `[mid, len)`.
+    /// Both halves can grow and shrink independently, see [`SplitAt`] for more information.
+    /// When the [`SplitAt`] is dropped, the slice covers both halves again.
+    /// 
     /// # Panics
-    ///
-    /// Panics if `index > len`.
-    ///
+    /// 
+    /// Panics if `mid > len`.
+    /// 
     /// # Examples
-    ///
+    /// 
     /// ```
     /// use vecslice::Slice;
     /// 
-    /// let mut vec = vec![0, 1, 2, 3];
-    /// let mut slice = vec.vecslice(1..=2);
-    /// assert_eq!(slice, [1, 2]);
-    /// slice.insert(1, 4);
-    /// assert_eq!(slice, [1, 4, 2]);
-    /// slice.insert(3, 5);
-    /// assert_eq!(slice, [1, 4, 2, 5]);
-    /// assert_eq!(vec, [0, 1, 4, 2, 5, 3]);
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5];
+    /// let mut slice = vec.vecslice(1..5);
+    /// 
+    /// let mut split = slice.split_at_mut(1);
+    /// split.left().push_back(6);
+    /// split.right().clear();
+    /// drop(split);
+    /// 
+    /// assert_eq!(slice, [1, 6]);
+    /// assert_eq!(vec, [0, 1, 6, 5]);
     /// ```
-    pub fn insert(&mut self, index: usize, element: T) {
-        assert!(index <= self.len());
-        self.vec.insert(self.start+index, element);
-        self.end += 1;
+    pub fn split_at_mut(&mut self, mid: usize) -> SplitAt<'_, T, S> {
+        assert!(mid <= self.len());
+        let limits = self.limits();
+        SplitAt::new(self.original, self.start, self.start+mid, self.end, limits, Some(&mut self.end))
     }
-    
-    /// Removes the last element from a VecSlice and returns it, or [`None`] if it
-    /// is empty.
+
+    /// Calls `f` with a growable sub-slice for every consecutive chunk of `chunk_len` elements of the slice.
     ///
-    /// If you'd like to pop the first element, use [`VecSlice::pop_front`] instead.
+    /// The last chunk is shorter if `chunk_len` does not divide the length of the slice.
+    /// Each sub-slice can insert and remove elements, the next one starts right after it,
+    /// and the slice covers all of them when the call returns.
     ///
-    /// [`VecSlice::pop_front`]: crate::VecSlice::pop_front
+    /// # Panics
+    ///
+    /// Panics if `chunk_len` is zero.
     ///
     /// # Examples
     ///
     /// ```
     /// use vecslice::Slice;
-    /// 
-    /// let mut vec = vec![0, 1, 2, 3];
-    /// let mut slice = vec.vecslice(..=2);
-    /// assert_eq!(slice, [0, 1, 2]);
-    /// assert_eq!(slice.pop_back(), Some(2));
-    /// assert_eq!(slice, [0, 1]);
-    /// assert_eq!(vec, [0, 1, 3]);
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5, 6];
+    /// let mut slice = vec.vecslice(1..6);
+    ///
+    /// // Prefix every chunk with its length
+    /// slice.for_each_subslice(2, |sub| {
+    ///     let len = sub.len();
+    ///     sub.push_front(len * 10);
+    /// });
+    /// assert_eq!(slice, [20, 1, 2, 20, 3, 4, 10, 5]);
+    /// assert_eq!(vec, [0, 20, 1, 2, 20, 3, 4, 10, 5, 6]);
     /// ```
-    pub fn pop_back(&mut self) -> Option<T> {
-        if !self.is_empty() {
-            self.end -= 1;
-            Some(self.vec.remove(self.end))
-        } else {
-            None
+    pub fn for_each_subslice<F: FnMut(&mut VecSlice<'_, T, S>)>(&mut self, chunk_len: usize, mut f: F) {
+        assert!(chunk_len != 0, "chunk size must be non-zero");
+        let mut start = self.start;
+        let mut remaining = self.len();
+        while remaining > 0 {
+            let len = chunk_len.min(remaining);
+            remaining -= len;
+            let original_len = self.original.len();
+            let mut sub = VecSlice::lent(start, start+len, &mut *self.original);
+            f(&mut sub);
+            // The sub-slice may have shrunk, the next chunk starts after all the elements of this one
+            start = start + len + self.original.len() - original_len;
         }
+        self.end = start;
     }
 
-    /// Removes the first element from a VecSlice and returns it, or [`None`] if it
-    /// is empty.
+    /// Splits the slice into several disjoint parts, one for every range in `ranges`, consuming it.
     ///
-    /// If you'd like to pop the last element, use [`VecSlice::pop_back`] instead.
+    /// The ranges are relative to the slice, they must be sorted and must not overlap, but don't need to cover the whole slice.
     ///
-    /// [`VecSlice::pop_back`]: crate::VecSlice::pop_back
+    /// The parts are lent one at a time through [`Splits::part`], as every one of them needs the mutable reference to the underlying buffer
+    /// to insert and remove elements. See [`Splits`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ranges are not sorted, overlap or are out of the bounds of the slice.
     ///
     /// # Examples
     ///
     /// ```
     /// use vecslice::Slice;
-    /// 
-    /// let mut vec = vec![0, 1, 2, 3];
-    /// let mut slice = vec.vecslice(1..);
-    /// assert_eq!(slice.pop_front(), Some(1));
-    /// assert_eq!(slice, [2, 3]);
-    /// assert_eq!(vec, [0, 2, 3]);
+    ///
+    /// let mut vec = vec![1, 2, 3, 4, 5];
+    /// let mut parts = vec.vecslice(..).into_split([0..1, 1..3, 4..5]);
+    /// assert_eq!(parts.len(), 3);
+    ///
+    /// for i in 0..parts.len() {
+    ///     let mut part = parts.part(i);
+    ///     let sum = part.iter().sum();
+    ///     part.push_back(sum);
+    /// }
+    /// assert_eq!(vec, [1, 1, 2, 3, 5, 4, 5, 5]);
     /// ```
-    pub fn pop_front(&mut self) -> Option<T> {
-        if !self.is_empty() {
-            self.end -= 1;
-            Some(self.vec.remove(self.start))
-        } else {
-            None
-        }
+    pub fn into_split<I>(self, ranges: I) -> Splits<'a, T, S>
+    where
+        I: IntoIterator<Item = core::ops::Range<usize>>,
+    {
+        let len = self.len();
+        let mut previous_end = 0;
+        let parts = ranges.into_iter().map(|range| {
+            assert!(previous_end <= range.start, "range {range:?} overlaps or is not sorted");
+            assert!(range.start <= range.end && range.end <= len, "range {range:?} out of range for slice of length {len}");
+            previous_end = range.end;
+            (self.start + range.start, self.start + range.end)
+        }).collect();
+        let limits = self.limits();
+        Splits::new(self.original, self.start, self.end, limits, parts)
     }
-    
-    /// Removes and returns the element at position `index` within the vector,
-    /// shifting all elements after it to the left.
+
+    /// Calls `f` with a growable sub-slice for every range in `ranges`.
+    ///
+    /// The ranges are relative to the slice before calling `f`, they must be sorted and must not overlap.
+    /// Each sub-slice can insert and remove elements, the following ranges are moved accordingly.
     ///
-    /// Note: Because this shifts over the remaining elements, it has a
-    /// worst-case performance of *O*(*n*).
-    /// 
     /// # Panics
     ///
-    /// Panics if `index > len`.
+    /// Panics if the ranges are not sorted, overlap or are out of the bounds of the slice.
     ///
     /// # Examples
     ///
     /// ```
     /// use vecslice::Slice;
-    /// 
-    /// let mut v = vec![1, 2, 3];
-    /// let mut slice = v.vecslice(0..2);
-    /// assert_eq!(slice.remove(1), 2);
-    /// assert_eq!(slice, [1]);
-    /// assert_eq!(v, [1, 3]);
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5, 6];
+    /// let mut slice = vec.vecslice(1..6);
+    ///
+    /// slice.for_each_subslice_in([0..2, 3..4], |sub| {
+    ///     sub.reverse();
+    ///     sub.push_back(0);
+    /// });
+    /// assert_eq!(slice, [2, 1, 0, 3, 4, 0, 5]);
+    /// assert_eq!(vec, [0, 2, 1, 0, 3, 4, 0, 5, 6]);
     /// ```
-    pub fn remove(&mut self, index: usize) -> T {
-        assert!(index <= self.len());
-        self.end -= 1;
-        self.vec.remove(self.start+index)
+    pub fn for_each_subslice_in<I, F>(&mut self, ranges: I, mut f: F)
+    where
+        I: IntoIterator<Item = core::ops::Range<usize>>,
+        F: FnMut(&mut VecSlice<'_, T, S>),
+    {
+        let len = self.len();
+        let original_len = self.original.len();
+        let mut previous_end = 0;
+        for range in ranges {
+            assert!(previous_end <= range.start, "range {range:?} overlaps or is not sorted");
+            assert!(range.start <= range.end && range.end <= len, "range {range:?} out of range for slice of length {len}");
+            previous_end = range.end;
+            // Elements before the range may have been inserted or removed by previous calls
+            let start = self.start + range.start + self.original.len() - original_len;
+            let mut sub = VecSlice::lent(start, start+range.len(), &mut *self.original);
+            f(&mut sub);
+        }
+        self.end = self.end + self.original.len() - original_len;
     }
     
-    /// Clears the slice, removing all values.
-    ///
-    /// Note that this method has no effect on the allocated capacity
-    /// of the underlying vector.
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the position of the first element of the slice in the underlying buffer.
     ///
     /// # Examples
     ///
     /// ```
     /// use vecslice::Slice;
-    /// 
-    /// let mut vec = vec![1, 2, 3];
-    /// let mut slice = vec.vecslice(1..);
-    ///
-    /// slice.clear();
     ///
-    /// assert!(slice.is_empty());
-    /// assert_eq!(vec, [1]);
+    /// let mut vec = vec![1, 2, 3, 4];
+    /// let mut slice = vec.vecslice(1..3);
+    /// assert_eq!(slice.start(), 1);
+    /// slice.push_front(0);
+    /// assert_eq!(slice.start(), 1);
     /// ```
-    pub fn clear(&mut self) {
-        self.drain(..);
+    pub fn start(&self) -> usize {
+        self.start
     }
-    
-    /// Removes the specified range from the slice in bulk, returning all
-    /// removed elements as an iterator. If the iterator is dropped before
-    /// being fully consumed, it drops the remaining removed elements.
-    ///
-    /// The returned iterator keeps a mutable borrow on the vector to optimize
-    /// its implementation.
-    ///
-    /// # Panics
-    ///
-    /// Panics if the starting point is greater than the end point or if
-    /// the end point is greater than the length of the vector.
-    ///
-    /// # Leaking
-    ///
-    /// If the returned iterator goes out of scope without being dropped (due to
-    /// [`mem::forget`], for example), the vector may have lost and leaked
-    /// elements arbitrarily, including elements outside the range.
+
+    /// Returns the position after the last element of the slice in the underlying buffer.
     ///
     /// # Examples
     ///
     /// ```
     /// use vecslice::Slice;
-    /// 
-    /// let mut vec = vec![0, 1, 2, 3, 4, 5];
-    /// let mut slice = vec.vecslice(2..);
-    /// assert_eq!(slice, [2, 3, 4, 5]);
-    /// let u: Vec<_> = slice.drain(1..=2).collect();
-    /// assert_eq!(slice, [2, 5]);
-    /// assert_eq!(u, &[3, 4]);
     ///
-    /// assert_eq!(vec, &[0, 1, 2, 5]);
-    /// 
-    /// let mut slice = vec.vecslice(1..=2);
-    /// assert_eq!(slice, [1, 2]);
-    /// slice.drain(..);
-    /// assert_eq!(slice, []);
-    /// assert_eq!(vec, &[0, 5]);
+    /// let mut vec = vec![1, 2, 3, 4];
+    /// let mut slice = vec.vecslice(1..3);
+    /// assert_eq!(slice.end(), 3);
+    /// slice.push_front(0);
+    /// assert_eq!(slice.end(), 4);
     /// ```
-    pub fn drain(&mut self, range: impl RangeBounds<usize>) -> std::vec::Drain<'_, T> {
-        let (start, end) = Self::translate_range(range, self.start, self.end);
-        self.end -= end - start; // Adjust length of the new slice
-        self.vec.drain(start..end)
+    pub fn end(&self) -> usize {
+        self.end
     }
-    
-    /// Copies `self` into a new `Vec`.
+
+    /// Returns the range of the slice in the underlying buffer.
     ///
     /// # Examples
     ///
     /// ```
     /// use vecslice::Slice;
-    /// 
-    /// let mut v = vec![1, 2, 3];
-    /// let slice = v.vecslice(0..2);
-    /// let s = slice.to_vec();
-    /// assert_eq!(s, [1, 2]);
-    /// assert_eq!(v, [1, 2, 3]);
-    /// // Here, `s` and `v` can be modified independently.
+    ///
+    /// let mut vec = vec![1, 2, 3, 4];
+    /// let mut slice = vec.vecslice(1..3);
+    /// slice.pop_back();
+    /// assert_eq!(slice.range(), 1..2);
     /// ```
-    pub fn to_vec(&self) -> Vec<T> where T: Clone {
-        self.vec[self.start..self.end].to_vec()
+    pub fn range(&self) -> core::ops::Range<usize> {
+        self.start..self.end
     }
-    
-    /// Consumes `self` into a new `Vec`.
+
+    /// Returns the number of elements of the underlying buffer, including the ones outside the slice.
     ///
     /// # Examples
     ///
     /// ```
     /// use vecslice::Slice;
-    /// 
-    /// let mut vec = vec![1, 2, 3];
-    /// let slice = vec.vecslice(0..2);
-    /// let s = slice.to_vec();
+    ///
+    /// let mut vec = vec![1, 2, 3, 4];
+    /// let mut slice = vec.vecslice(1..3);
+    /// slice.push_back(5);
+    /// assert_eq!(slice.original_len(), 5);
+    /// ```
+    pub fn original_len(&self) -> usize {
+        self.original.len()
+    }
+
+    /// Extends the slice over the `n` elements of the underlying buffer right before it, without moving any element.
+    ///
+    /// A slice lent by another one, like the sub-slices of [`VecSlice::for_each_subslice`] or the halves of [`VecSlice::split_at`],
+    /// can only grow back over the elements it was lent with, as its lender keeps track of them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are less than `n` elements before the slice that it can grow over.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![1, 2, 3, 4];
+    /// let mut slice = vec.vecslice(2..3);
+    /// slice.grow_front(2);
+    /// assert_eq!(slice, [1, 2, 3]);
+    /// ```
+    pub fn grow_front(&mut self, n: usize) {
+        let before = self.room_before();
+        assert!(n <= before, "grow_front amount (is {n}) should be <= elements before the slice (is {before})");
+        self.start -= n;
+    }
+
+    /// Extends the slice over the `n` elements of the underlying buffer right after it, without moving any element.
+    ///
+    /// A slice lent by another one, like the sub-slices of [`VecSlice::for_each_subslice`] or the halves of [`VecSlice::split_at`],
+    /// can only grow back over the elements it was lent with, as its lender keeps track of them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are less than `n` elements after the slice that it can grow over.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![1, 2, 3, 4];
+    /// let mut slice = vec.vecslice(..1);
+    /// slice.grow_back(2);
+    /// assert_eq!(slice, [1, 2, 3]);
+    /// ```
+    pub fn grow_back(&mut self, n: usize) {
+        let after = self.room_after();
+        assert!(n <= after, "grow_back amount (is {n}) should be <= elements after the slice (is {after})");
+        self.end += n;
+    }
+
+    /// Excludes the first `n` elements from the slice, leaving them in the underlying buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than the length of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![1, 2, 3, 4];
+    /// let mut slice = vec.vecslice(..3);
+    /// slice.shrink_front(2);
+    /// assert_eq!(slice, [3]);
+    /// assert_eq!(vec, [1, 2, 3, 4]);
+    /// ```
+    pub fn shrink_front(&mut self, n: usize) {
+        assert!(n <= self.len(), "shrink_front amount (is {n}) should be <= len (is {})", self.len());
+        self.start += n;
+    }
+
+    /// Excludes the last `n` elements from the slice, leaving them in the underlying buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than the length of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![1, 2, 3, 4];
+    /// let mut slice = vec.vecslice(1..);
+    /// slice.shrink_back(2);
+    /// assert_eq!(slice, [2]);
+    /// assert_eq!(vec, [1, 2, 3, 4]);
+    /// ```
+    pub fn shrink_back(&mut self, n: usize) {
+        assert!(n <= self.len(), "shrink_back amount (is {n}) should be <= len (is {})", self.len());
+        self.end -= n;
+    }
+
+    /// Moves the slice `n` positions to the left over the underlying buffer, keeping its length and without moving any element.
+    ///
+    /// Like [`VecSlice::grow_front`], a slice lent by another one can't move past the elements it was lent with.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are less than `n` elements before the slice that it can move over.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![1, 2, 3, 4];
+    /// let mut slice = vec.vecslice(2..);
+    /// slice.shift_left(2);
+    /// assert_eq!(slice, [1, 2]);
+    /// ```
+    pub fn shift_left(&mut self, n: usize) {
+        let before = self.room_before();
+        assert!(n <= before, "shift_left amount (is {n}) should be <= elements before the slice (is {before})");
+        self.start -= n;
+        self.end -= n;
+    }
+
+    /// Moves the slice `n` positions to the right over the underlying buffer, keeping its length and without moving any element.
+    ///
+    /// Like [`VecSlice::grow_back`], a slice lent by another one can't move past the elements it was lent with.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are less than `n` elements after the slice that it can move over.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec: Vec<i32> = (0..10).collect();
+    /// let mut window = vec.vecslice(..3);
+    /// let mut sums = vec![window.iter().sum::<i32>()];
+    /// while window.end() < window.original_len() {
+    ///     window.shift_right(1);
+    ///     sums.push(window.iter().sum());
+    /// }
+    /// assert_eq!(sums, [3, 6, 9, 12, 15, 18, 21, 24]);
+    /// ```
+    pub fn shift_right(&mut self, n: usize) {
+        let after = self.room_after();
+        assert!(n <= after, "shift_right amount (is {n}) should be <= elements after the slice (is {after})");
+        self.start += n;
+        self.end += n;
+    }
+
+    /// Returns how many more elements can be added to the slice, or [`None`] if the underlying buffer is unbounded.
+    ///
+    /// A [`Vec`] can always grow, so a slice over one always returns [`None`].
+    /// For other buffers the limit is given by [`Sliceable::capacity`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![1, 2, 3];
+    /// let slice = vec.vecslice(1..);
+    /// assert_eq!(slice.remaining_capacity(), None);
+    /// ```
+    pub fn remaining_capacity(&self) -> Option<usize> {
+        self.original.capacity().map(|capacity| capacity - self.original.len())
+    }
+
+    /// Returns `true` if no more elements can be added to the slice.
+    ///
+    /// A slice over an unbounded buffer like [`Vec`] is never full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![1, 2, 3];
+    /// let slice = vec.vecslice(..);
+    /// assert!(!slice.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.remaining_capacity() == Some(0)
+    }
+
+    /// Appends an element to the back of a collection.
+    /// 
+    /// If you'd like to push at the front of the collection, use [`VecSlice::push_front`] instead.
+    ///
+    /// [`VecSlice::push_front`]: crate::VecSlice::push_front
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    /// 
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let mut slice = vec.vecslice(1..=2);
+    /// assert_eq!(slice, [1, 2]);
+    /// slice.push_back(4);
+    /// slice.push_back(5);
+    /// assert_eq!(slice, [1, 2, 4, 5]);
+    /// assert_eq!(vec, [0, 1, 2, 4, 5, 3]);
+    /// ```
+    pub fn push_back(&mut self, element: T) {
+        self.original.insert(self.end, element);
+        self.end += 1;
+    }
+
+    /// Appends an element to the front of a collection.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    /// 
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let mut slice = vec.vecslice(1..=2);
+    /// assert_eq!(slice, [1, 2]);
+    /// slice.push_front(4);
+    /// slice.push_front(5);
+    /// assert_eq!(slice, [5, 4, 1, 2]);
+    /// assert_eq!(vec, [0, 5, 4, 1, 2, 3]);
+    /// ```
+    pub fn push_front(&mut self, element: T) {
+        self.original.insert(self.start, element);
+        self.end += 1;
+    }
+    
+    /// Inserts an element at position `index` within the slice, shifting all
+    /// elements after it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    /// 
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let mut slice = vec.vecslice(1..=2);
+    /// assert_eq!(slice, [1, 2]);
+    /// slice.insert(1, 4);
+    /// assert_eq!(slice, [1, 4, 2]);
+    /// slice.insert(3, 5);
+    /// assert_eq!(slice, [1, 4, 2, 5]);
+    /// assert_eq!(vec, [0, 1, 4, 2, 5, 3]);
+    /// ```
+    pub fn insert(&mut self, index: usize, element: T) {
+        assert!(index <= self.len());
+        self.original.insert(self.start+index, element);
+        self.end += 1;
+    }
+
+    /// Inserts an element at position `index` within the slice, or returns an error if `index > len`.
+    /// 
+    /// If an error is returned, `element` is dropped.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use vecslice::{Slice, VecSliceError};
+    /// 
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let mut slice = vec.vecslice(1..=2);
+    /// assert_eq!(slice.try_insert(2, 4), Ok(()));
+    /// assert_eq!(slice.try_insert(4, 5), Err(VecSliceError::IndexOutOfBounds { index: 4, len: 3 }));
+    /// assert_eq!(vec, [0, 1, 2, 4, 3]);
+    /// ```
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), VecSliceError> {
+        if index > self.len() {
+            return Err(VecSliceError::IndexOutOfBounds { index, len: self.len() });
+        }
+        self.insert(index, element);
+        Ok(())
+    }
+
+    /// Inserts all the elements of `iter` at position `index` within the slice, keeping their order.
+    ///
+    /// The elements after `index` are shifted only once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let mut slice = vec.vecslice(1..3);
+    ///
+    /// slice.insert_many(1, [4, 5]);
+    /// assert_eq!(slice, [1, 4, 5, 2]);
+    /// assert_eq!(vec, [0, 1, 4, 5, 2, 3]);
+    /// ```
+    pub fn insert_many<I: IntoIterator<Item = T>>(&mut self, index: usize, iter: I) {
+        assert!(index <= self.len(), "insertion index (is {index}) should be <= len (is {})", self.len());
+        let index = self.start + index;
+        self.end += self.original.splice_range(index, index, iter);
+    }
+
+    /// Clones and inserts all the elements of `values` at position `index` within the slice.
+    ///
+    /// The elements after `index` are shifted only once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let mut slice = vec.vecslice(1..3);
+    ///
+    /// slice.insert_slice(2, &[4, 5]);
+    /// assert_eq!(slice, [1, 2, 4, 5]);
+    /// assert_eq!(vec, [0, 1, 2, 4, 5, 3]);
+    /// ```
+    pub fn insert_slice(&mut self, index: usize, values: &[T]) where T: Clone {
+        self.insert_many(index, values.iter().cloned());
+    }
+    
+    /// Removes the last element from a VecSlice and returns it, or [`None`] if it
+    /// is empty.
+    ///
+    /// If you'd like to pop the first element, use [`VecSlice::pop_front`] instead.
+    ///
+    /// [`VecSlice::pop_front`]: crate::VecSlice::pop_front
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    /// 
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let mut slice = vec.vecslice(..=2);
+    /// assert_eq!(slice, [0, 1, 2]);
+    /// assert_eq!(slice.pop_back(), Some(2));
+    /// assert_eq!(slice, [0, 1]);
+    /// assert_eq!(vec, [0, 1, 3]);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        if !self.is_empty() {
+            self.end -= 1;
+            Some(self.original.remove(self.end))
+        } else {
+            None
+        }
+    }
+
+    /// Removes the first element from a VecSlice and returns it, or [`None`] if it
+    /// is empty.
+    ///
+    /// If you'd like to pop the last element, use [`VecSlice::pop_back`] instead.
+    ///
+    /// [`VecSlice::pop_back`]: crate::VecSlice::pop_back
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    /// 
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let mut slice = vec.vecslice(1..);
+    /// assert_eq!(slice.pop_front(), Some(1));
+    /// assert_eq!(slice, [2, 3]);
+    /// assert_eq!(vec, [0, 2, 3]);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        if !self.is_empty() {
+            self.end -= 1;
+            Some(self.original.remove(self.start))
+        } else {
+            None
+        }
+    }
+
+    /// Removes and returns the last element of the slice if the predicate returns `true`,
+    /// or [`None`] if the predicate returns `false` or the slice is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![1, 2, 3, 4];
+    /// let mut slice = vec.vecslice(..3);
+    /// let pred = |x: &mut i32| *x % 2 == 1;
+    ///
+    /// assert_eq!(slice.pop_back_if(pred), Some(3));
+    /// assert_eq!(slice.pop_back_if(pred), None);
+    /// assert_eq!(slice, [1, 2]);
+    /// assert_eq!(vec, [1, 2, 4]);
+    /// ```
+    pub fn pop_back_if(&mut self, predicate: impl FnOnce(&mut T) -> bool) -> Option<T> {
+        let last = self.last_mut()?;
+        if predicate(last) { self.pop_back() } else { None }
+    }
+
+    /// Removes and returns the first element of the slice if the predicate returns `true`,
+    /// or [`None`] if the predicate returns `false` or the slice is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let mut slice = vec.vecslice(1..);
+    /// let pred = |x: &mut i32| *x % 2 == 1;
+    ///
+    /// assert_eq!(slice.pop_front_if(pred), Some(1));
+    /// assert_eq!(slice.pop_front_if(pred), None);
+    /// assert_eq!(slice, [2, 3]);
+    /// assert_eq!(vec, [0, 2, 3]);
+    /// ```
+    pub fn pop_front_if(&mut self, predicate: impl FnOnce(&mut T) -> bool) -> Option<T> {
+        let first = self.first_mut()?;
+        if predicate(first) { self.pop_front() } else { None }
+    }
+
+    /// Removes the last `n` elements of the slice, or all of them if it is shorter,
+    /// and returns them in their original order.
+    ///
+    /// The rest of the vector is shifted only once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4];
+    /// let mut slice = vec.vecslice(..4);
+    ///
+    /// assert_eq!(slice.pop_back_n(2), [2, 3]);
+    /// assert_eq!(slice.pop_back_n(5), [0, 1]);
+    /// assert_eq!(slice, []);
+    /// assert_eq!(vec, [4]);
+    /// ```
+    pub fn pop_back_n(&mut self, n: usize) -> Vec<T> {
+        let len = self.len();
+        self.drain(len - n.min(len)..).collect()
+    }
+
+    /// Removes the first `n` elements of the slice, or all of them if it is shorter,
+    /// and returns them in their original order.
+    ///
+    /// The rest of the vector is shifted only once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4];
+    /// let mut slice = vec.vecslice(1..);
+    ///
+    /// assert_eq!(slice.pop_front_n(2), [1, 2]);
+    /// assert_eq!(slice.pop_front_n(5), [3, 4]);
+    /// assert_eq!(slice, []);
+    /// assert_eq!(vec, [0]);
+    /// ```
+    pub fn pop_front_n(&mut self, n: usize) -> Vec<T> {
+        let len = self.len();
+        self.drain(..n.min(len)).collect()
+    }
+    
+    /// Removes and returns the element at position `index` within the vector,
+    /// shifting all elements after it to the left.
+    ///
+    /// Note: Because this shifts over the remaining elements, it has a
+    /// worst-case performance of *O*(*n*).
+    /// 
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    /// 
+    /// let mut v = vec![1, 2, 3];
+    /// let mut slice = v.vecslice(0..2);
+    /// assert_eq!(slice.remove(1), 2);
+    /// assert_eq!(slice, [1]);
+    /// assert_eq!(v, [1, 3]);
+    /// ```
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len(), "index {index} out of range for slice of length {}", self.len());
+        self.end -= 1;
+        self.original.remove(self.start+index)
+    }
+
+    /// Removes and returns the element at position `index` within the slice, or returns an error if `index >= len`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use vecslice::{Slice, VecSliceError};
+    /// 
+    /// let mut vec = vec![1, 2, 3];
+    /// let mut slice = vec.vecslice(0..2);
+    /// assert_eq!(slice.try_remove(2), Err(VecSliceError::IndexOutOfBounds { index: 2, len: 2 }));
+    /// assert_eq!(slice.try_remove(1), Ok(2));
+    /// assert_eq!(vec, [1, 3]);
+    /// ```
+    pub fn try_remove(&mut self, index: usize) -> Result<T, VecSliceError> {
+        if index >= self.len() {
+            return Err(VecSliceError::IndexOutOfBounds { index, len: self.len() });
+        }
+        Ok(self.remove(index))
+    }
+
+    /// Removes an element from the slice and returns it.
+    ///
+    /// The removed element is replaced by the last element of the slice,
+    /// so only the elements after the slice are shifted.
+    ///
+    /// This does not preserve ordering of the remaining elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4];
+    /// let mut slice = vec.vecslice(..4);
+    /// assert_eq!(slice.swap_remove(1), 1);
+    /// assert_eq!(slice, [0, 3, 2]);
+    /// assert_eq!(vec, [0, 3, 2, 4]);
+    /// ```
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        assert!(index < len, "swap_remove index (is {index}) should be < len (is {len})");
+        self.as_mut_slice().swap(index, len - 1);
+        self.end -= 1;
+        self.original.remove(self.end)
+    }
+
+    /// Removes an element from the slice and returns it.
+    ///
+    /// The removed element is replaced by the first element of the slice,
+    /// so only the first element and the elements after the slice are shifted.
+    ///
+    /// This does not preserve ordering of the remaining elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4];
+    /// let mut slice = vec.vecslice(1..);
+    /// assert_eq!(slice.swap_remove_front(2), 3);
+    /// assert_eq!(slice, [2, 1, 4]);
+    /// assert_eq!(vec, [0, 2, 1, 4]);
+    /// ```
+    pub fn swap_remove_front(&mut self, index: usize) -> T {
+        let len = self.len();
+        assert!(index < len, "swap_remove_front index (is {index}) should be < len (is {len})");
+        self.as_mut_slice().swap(index, 0);
+        self.end -= 1;
+        self.original.remove(self.start)
+    }
+
+    /// Removes the elements at the given `indices` within the slice, shifting the remaining elements to the left.
+    ///
+    /// The indices can be in any order and may contain duplicates.
+    /// All the elements are removed in a single pass, so it is *O*(*n*) instead of calling [`VecSlice::remove`] for every index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is `>= len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5, 6];
+    /// let mut slice = vec.vecslice(1..6);
+    /// slice.remove_indices(&[3, 0, 3, 1]);
+    /// assert_eq!(slice, [3, 5]);
+    /// assert_eq!(vec, [0, 3, 5, 6]);
+    /// ```
+    pub fn remove_indices(&mut self, indices: &[usize]) {
+        let mut indices = indices.to_vec();
+        indices.sort_unstable();
+        indices.dedup();
+        if let Some(&last) = indices.last() {
+            assert!(last < self.len());
+        }
+
+        let slice = self.as_mut_slice();
+        let mut removed = indices.iter().peekable();
+        let mut write = 0;
+        for read in 0..slice.len() {
+            if removed.next_if_eq(&&read).is_none() {
+                slice.swap(write, read);
+                write += 1;
+            }
+        }
+        self.drain(write..);
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, remove all elements `e` for which `f(&e)` returns `false`.
+    /// This method operates in place, visiting each element exactly once in the
+    /// original order, and preserves the order of the retained elements.
+    /// The rest of the vector is shifted only once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![1, 2, 3, 4, 5, 6];
+    /// let mut slice = vec.vecslice(1..5);
+    /// slice.retain(|&x| x % 2 == 0);
+    /// assert_eq!(slice, [2, 4]);
+    /// assert_eq!(vec, [1, 2, 4, 6]);
+    /// ```
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.retain_mut(|elem| f(elem));
+    }
+
+    /// Retains only the elements specified by the predicate, passing a mutable reference to it.
+    ///
+    /// In other words, remove all elements `e` such that `f(&mut e)` returns `false`.
+    /// This method operates in place, visiting each element exactly once in the
+    /// original order, and preserves the order of the retained elements.
+    /// The rest of the vector is shifted only once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![1, 2, 3, 4, 5, 6];
+    /// let mut slice = vec.vecslice(1..5);
+    /// slice.retain_mut(|x| if *x <= 3 {
+    ///     *x += 1;
+    ///     true
+    /// } else {
+    ///     false
+    /// });
+    /// assert_eq!(slice, [3, 4]);
+    /// assert_eq!(vec, [1, 3, 4, 6]);
+    /// ```
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        let slice = self.as_mut_slice();
+        let mut kept = 0;
+        for i in 0..slice.len() {
+            if f(&mut slice[i]) {
+                slice.swap(kept, i);
+                kept += 1;
+            }
+        }
+        self.drain(kept..);
+    }
+
+    /// Removes consecutive repeated elements in the slice according to the [`PartialEq`] trait implementation.
+    ///
+    /// If the slice is sorted, this removes all duplicates.
+    /// The rest of the vector is shifted only once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![1, 1, 2, 2, 3, 2, 2];
+    /// let mut slice = vec.vecslice(1..6);
+    /// slice.dedup();
+    /// assert_eq!(slice, [1, 2, 3, 2]);
+    /// assert_eq!(vec, [1, 1, 2, 3, 2, 2]);
+    /// ```
+    pub fn dedup(&mut self) where T: PartialEq {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Removes all but the first of consecutive elements in the slice that resolve to the same key.
+    ///
+    /// If the slice is sorted, this removes all duplicates.
+    /// The rest of the vector is shifted only once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![10, 20, 21, 30, 20];
+    /// let mut slice = vec.vecslice(..4);
+    /// slice.dedup_by_key(|i| *i / 10);
+    /// assert_eq!(slice, [10, 20, 30]);
+    /// assert_eq!(vec, [10, 20, 30, 20]);
+    /// ```
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Removes all but the first of consecutive elements in the slice satisfying a given equality relation.
+    ///
+    /// The `same_bucket` function is passed references to two elements from the slice and
+    /// must determine if the elements compare equal. The elements are passed in opposite order
+    /// from their order in the slice, so if `same_bucket(a, b)` returns `true`, `a` is removed.
+    /// The rest of the vector is shifted only once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec!["foo", "bar", "Bar", "baz", "bar"];
+    /// let mut slice = vec.vecslice(1..);
+    /// slice.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    /// assert_eq!(slice, ["bar", "baz", "bar"]);
+    /// assert_eq!(vec, ["foo", "bar", "baz", "bar"]);
+    /// ```
+    pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, mut same_bucket: F) {
+        let slice = self.as_mut_slice();
+        if slice.is_empty() {
+            return;
+        }
+        let mut kept = 1;
+        for i in 1..slice.len() {
+            let (front, back) = slice.split_at_mut(i);
+            if !same_bucket(&mut back[0], &mut front[kept - 1]) {
+                slice.swap(kept, i);
+                kept += 1;
+            }
+        }
+        self.drain(kept..);
+    }
+
+    /// Creates an iterator which uses a closure to determine if an element of the slice should be removed.
+    ///
+    /// If the closure returns `true`, the element is removed and yielded. If the closure returns `false`,
+    /// the element remains in the slice and will not be yielded by the iterator.
+    ///
+    /// The elements are removed lazily as the iterator advances, so if it is dropped before being fully consumed
+    /// the remaining elements are kept in the slice, in their original order.
+    ///
+    /// Note that every extracted element shifts the rest of the vector, use [`VecSlice::retain`]
+    /// if you don't need the removed elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5, 6, 7];
+    /// let mut slice = vec.vecslice(1..7);
+    ///
+    /// let evens: Vec<_> = slice.extract_if(|x| *x % 2 == 0).collect();
+    /// assert_eq!(evens, [2, 4, 6]);
+    /// assert_eq!(slice, [1, 3, 5]);
+    /// assert_eq!(vec, [0, 1, 3, 5, 7]);
+    /// ```
+    pub fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, pred: F) -> ExtractIf<'_, 'a, T, S, F> {
+        ExtractIf::new(self, pred)
+    }
+
+    /// Applies `f` to every element of the slice, keeping the returned value if it is [`Some`] and removing the element if it is [`None`].
+    ///
+    /// The order of the kept elements is preserved.
+    ///
+    /// The elements are moved out of the vector and back in bulk, so the rest of the vector is shifted twice.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the element it was given is lost, but the already processed and the unprocessed elements are kept in the slice,
+    /// in their original order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5];
+    /// let mut slice = vec.vecslice(1..5);
+    ///
+    /// slice.filter_map_in_place(|x| (x % 2 == 0).then_some(x * 2));
+    /// assert_eq!(slice, [4, 8]);
+    /// assert_eq!(vec, [0, 4, 8, 5]);
+    /// ```
+    pub fn filter_map_in_place<F: FnMut(T) -> Option<T>>(&mut self, mut f: F) {
+        // Every slot is emptied before giving its element to `f`, so if it panics the other elements are still there
+        let mut taken: Vec<Option<T>> = self.drain(..).map(Some).collect();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            for slot in &mut taken {
+                *slot = slot.take().and_then(&mut f);
+            }
+        }));
+        self.end += self.original.splice_range(self.start, self.start, taken.into_iter().flatten());
+        if let Err(payload) = result {
+            std::panic::resume_unwind(payload);
+        }
+    }
+
+    /// Clears the slice, removing all values.
+    ///
+    /// Note that this method has no effect on the allocated capacity
+    /// of the underlying vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    /// 
+    /// let mut vec = vec![1, 2, 3];
+    /// let mut slice = vec.vecslice(1..);
+    ///
+    /// slice.clear();
+    ///
+    /// assert!(slice.is_empty());
+    /// assert_eq!(vec, [1]);
+    /// ```
+    pub fn clear(&mut self) {
+        self.original.clear_range(self.start, self.end);
+        self.end = self.start;
+    }
+    
+    /// Shortens the slice, keeping the first `len` elements and removing the rest from the vector.
+    ///
+    /// If `len` is greater or equal to the slice's current length, this has no effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5];
+    /// let mut slice = vec.vecslice(1..5);
+    ///
+    /// slice.truncate(2);
+    /// assert_eq!(slice, [1, 2]);
+    /// slice.truncate(3);
+    /// assert_eq!(slice, [1, 2]);
+    /// assert_eq!(vec, [0, 1, 2, 5]);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            self.original.clear_range(self.start+len, self.end);
+            self.end = self.start+len;
+        }
+    }
+
+    /// Shortens the slice, keeping the last `len` elements and removing the rest from the vector.
+    ///
+    /// If `len` is greater or equal to the slice's current length, this has no effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5];
+    /// let mut slice = vec.vecslice(1..5);
+    ///
+    /// slice.truncate_front(2);
+    /// assert_eq!(slice, [3, 4]);
+    /// slice.truncate_front(3);
+    /// assert_eq!(slice, [3, 4]);
+    /// assert_eq!(vec, [0, 3, 4, 5]);
+    /// ```
+    pub fn truncate_front(&mut self, len: usize) {
+        if len < self.len() {
+            let removed = self.len() - len;
+            self.original.clear_range(self.start, self.start+removed);
+            self.end -= removed;
+        }
+    }
+    
+    /// Removes `prefix` from the start of the slice, returning `true` if the slice started with it.
+    ///
+    /// If the slice does not start with `prefix`, it is left unchanged and `false` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let mut slice = vec.vecslice(1..);
+    ///
+    /// assert!(!slice.strip_prefix(&[2]));
+    /// assert!(slice.strip_prefix(&[1, 2]));
+    /// assert_eq!(slice, [3]);
+    /// assert_eq!(vec, [0, 3]);
+    /// ```
+    pub fn strip_prefix(&mut self, prefix: &[T]) -> bool where T: PartialEq {
+        if self.starts_with(prefix) {
+            self.truncate_front(self.len() - prefix.len());
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Removes `suffix` from the end of the slice, returning `true` if the slice ended with it.
+    ///
+    /// If the slice does not end with `suffix`, it is left unchanged and `false` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let mut slice = vec.vecslice(..3);
+    ///
+    /// assert!(!slice.strip_suffix(&[1]));
+    /// assert!(slice.strip_suffix(&[1, 2]));
+    /// assert_eq!(slice, [0]);
+    /// assert_eq!(vec, [0, 3]);
+    /// ```
+    pub fn strip_suffix(&mut self, suffix: &[T]) -> bool where T: PartialEq {
+        if self.ends_with(suffix) {
+            self.truncate(self.len() - suffix.len());
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Splits the slice into two at the given index.
+    ///
+    /// Returns a newly allocated vector containing the elements in the range
+    /// `[at, len)`, which are removed from the underlying vector.
+    /// After the call, the slice will be left containing the elements `[0, at)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4];
+    /// let mut slice = vec.vecslice(1..4);
+    ///
+    /// let tail = slice.split_off(1);
+    /// assert_eq!(slice, [1]);
+    /// assert_eq!(tail, [2, 3]);
+    /// assert_eq!(vec, [0, 1, 4]);
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Vec<T> {
+        assert!(at <= self.len(), "`at` split index (is {at}) should be <= len (is {})", self.len());
+        self.drain(at..).collect()
+    }
+
+    /// Returns a [`Cursor`] pointing to the element at position `pos`, to edit the slice around it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pos > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![1, 2, 3, 4];
+    /// let mut slice = vec.vecslice(..);
+    ///
+    /// // Duplicate every even element
+    /// let mut cursor = slice.cursor(0);
+    /// while let Some(&x) = cursor.peek() {
+    ///     if x % 2 == 0 {
+    ///         cursor.insert_before(x);
+    ///     }
+    ///     cursor.move_right();
+    /// }
+    /// assert_eq!(vec, [1, 2, 2, 3, 4, 4]);
+    /// ```
+    pub fn cursor(&mut self, pos: usize) -> Cursor<'_, 'a, T, S> {
+        Cursor::new(self, pos)
+    }
+
+    /// Moves all the elements of `other` to the end of the slice, leaving `other` empty.
+    ///
+    /// The elements after the slice are shifted only once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let mut slice = vec.vecslice(1..3);
+    /// let mut other = vec![4, 5];
+    ///
+    /// slice.append(&mut other);
+    /// assert_eq!(slice, [1, 2, 4, 5]);
+    /// assert_eq!(other, []);
+    /// assert_eq!(vec, [0, 1, 2, 4, 5, 3]);
+    /// ```
+    #[cfg(not(feature = "allocator_api"))]
+    pub fn append(&mut self, other: &mut Vec<T>) {
+        self.end += self.original.splice_range(self.end, self.end, other.drain(..));
+    }
+
+    /// Moves all the elements of `other` to the end of the slice, leaving `other` empty.
+    ///
+    /// The elements after the slice are shifted only once.
+    #[cfg(feature = "allocator_api")]
+    pub fn append<A: std::alloc::Allocator>(&mut self, other: &mut Vec<T, A>) {
+        self.end += self.original.splice_range(self.end, self.end, other.drain(..));
+    }
+
+    /// Inserts all the elements of `iter` at the front of the slice, keeping their order.
+    ///
+    /// The elements of the slice are shifted only once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let mut slice = vec.vecslice(2..);
+    ///
+    /// slice.extend_front([4, 5]);
+    /// assert_eq!(slice, [4, 5, 2, 3]);
+    /// assert_eq!(vec, [0, 1, 4, 5, 2, 3]);
+    /// ```
+    pub fn extend_front<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.end += self.original.splice_range(self.start, self.start, iter);
+    }
+
+    /// Clones the elements in `src` and appends them to the end of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point is greater than the length of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4];
+    /// let mut slice = vec.vecslice(1..3);
+    ///
+    /// slice.extend_from_within(..);
+    /// assert_eq!(slice, [1, 2, 1, 2]);
+    /// slice.extend_from_within(1..3);
+    /// assert_eq!(slice, [1, 2, 1, 2, 2, 1]);
+    /// assert_eq!(vec, [0, 1, 2, 1, 2, 2, 1, 3, 4]);
+    /// ```
+    pub fn extend_from_within<R: RangeBounds<usize>>(&mut self, src: R) where T: Clone {
+        let (start, end) = Self::translate_range(src, 0, self.len());
+        let cloned = self.as_slice()[start..end].to_vec();
+        self.end += self.original.splice_range(self.end, self.end, cloned);
+    }
+
+    /// Removes the specified range from the slice in bulk, returning all
+    /// removed elements as an iterator. If the iterator is dropped before
+    /// being fully consumed, it drops the remaining removed elements.
+    ///
+    /// The returned iterator keeps a mutable borrow on the vector to optimize
+    /// its implementation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if
+    /// the end point is greater than the length of the slice.
+    ///
+    /// # Leaking
+    ///
+    /// If the returned iterator goes out of scope without being dropped (due to
+    /// [`mem::forget`], for example), the vector may have lost and leaked
+    /// elements arbitrarily, including elements outside the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    /// 
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5];
+    /// let mut slice = vec.vecslice(2..);
+    /// assert_eq!(slice, [2, 3, 4, 5]);
+    /// let u: Vec<_> = slice.drain(1..=2).collect();
+    /// assert_eq!(slice, [2, 5]);
+    /// assert_eq!(u, &[3, 4]);
+    ///
+    /// assert_eq!(vec, &[0, 1, 2, 5]);
+    /// 
+    /// let mut slice = vec.vecslice(1..=2);
+    /// assert_eq!(slice, [1, 2]);
+    /// slice.drain(..);
+    /// assert_eq!(slice, []);
+    /// assert_eq!(vec, &[0, 5]);
+    /// ```
+    pub fn drain(&mut self, range: impl RangeBounds<usize>) -> Drain<'_, T, S> {
+        let (start, end) = Self::translate_range(range, self.start, self.end);
+        assert!(start <= end && end <= self.end);
+        self.end -= end - start; // Adjust length of the new slice
+        Drain::new(self.original, start, end, Some(&mut self.end))
+    }
+
+    /// Removes the specified range from the slice in bulk like [`VecSlice::drain`],
+    /// or returns an error if the range is out of the bounds of the slice.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use vecslice::{Slice, VecSliceError};
+    /// 
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5];
+    /// let mut slice = vec.vecslice(2..);
+    /// assert_eq!(slice.try_drain(3..5).unwrap_err(), VecSliceError::EndOutOfBounds { end: 5, len: 4 });
+    /// assert_eq!(slice.try_drain(1..=2).unwrap().collect::<Vec<_>>(), [3, 4]);
+    /// assert_eq!(vec, [0, 1, 2, 5]);
+    /// ```
+    pub fn try_drain(&mut self, range: impl RangeBounds<usize>) -> Result<Drain<'_, T, S>, VecSliceError> {
+        let (start, end) = Self::try_translate_range(range, self.len())?;
+        Ok(self.drain(start..end))
+    }
+
+    /// Replaces the specified range in the slice with the given `replace_with` iterator
+    /// and returns an iterator over the removed items.
+    ///
+    /// `replace_with` does not need to be the same length as `range`.
+    /// Unlike [`Vec::splice`], the replacement happens immediately, not when the returned iterator is dropped.
+    ///
+    /// The removed elements are swapped in place with the new ones, so the rest of the vector
+    /// is shifted at most once, to make room for the extra elements or to close the gap left by the missing ones.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if
+    /// the end point is greater than the length of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4];
+    /// let mut slice = vec.vecslice(1..4);
+    ///
+    /// let removed: Vec<_> = slice.splice(1..2, [7, 8, 9]).collect();
+    /// assert_eq!(removed, [2]);
+    /// assert_eq!(slice, [1, 7, 8, 9, 3]);
+    ///
+    /// let removed: Vec<_> = slice.splice(..4, [6]).collect();
+    /// assert_eq!(removed, [1, 7, 8, 9]);
+    /// assert_eq!(slice, [6, 3]);
+    /// assert_eq!(vec, [0, 6, 3, 4]);
+    /// ```
+    pub fn splice<R: RangeBounds<usize>, I: IntoIterator<Item = T>>(&mut self, range: R, replace_with: I) -> std::vec::IntoIter<T> {
+        let (start, end) = Self::translate_range(range, self.start, self.end);
+        assert!(start <= end && end <= self.end);
+
+        let mut replace_with = replace_with.into_iter();
+        let mut removed = Vec::with_capacity(end - start);
+        let mut index = start;
+        while index < end {
+            let Some(element) = replace_with.next() else {
+                break;
+            };
+            removed.push(core::mem::replace(&mut self.original.as_mut_range(index, index + 1)[0], element));
+            index += 1;
+        }
+
+        if index < end {
+            removed.extend(Drain::new(self.original, index, end, None));
+            self.end -= end - index;
+        } else {
+            self.end += self.original.splice_range(end, end, replace_with);
+        }
+        removed.into_iter()
+    }
+
+    /// Replaces the specified range of the slice with clones of `replacement`.
+    ///
+    /// If `replacement` has the same length as the range, the elements are overwritten in place without shifting the rest of the vector,
+    /// otherwise the slice grows or shrinks to fit the new elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if
+    /// the end point is greater than the length of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5];
+    /// let mut slice = vec.vecslice(1..5);
+    ///
+    /// slice.overwrite_range(1..3, &[7, 8]);
+    /// assert_eq!(slice, [1, 7, 8, 4]);
+    ///
+    /// slice.overwrite_range(..1, &[9, 9, 9]);
+    /// assert_eq!(slice, [9, 9, 9, 7, 8, 4]);
+    ///
+    /// slice.overwrite_range(3.., &[6]);
+    /// assert_eq!(slice, [9, 9, 9, 6]);
+    /// assert_eq!(vec, [0, 9, 9, 9, 6, 5]);
+    /// ```
+    pub fn overwrite_range<R: RangeBounds<usize>>(&mut self, range: R, replacement: &[T]) where T: Clone {
+        let (start, end) = Self::translate_range(range, self.start, self.end);
+        assert!(start <= end && end <= self.end);
+        if end - start == replacement.len() {
+            self.original.as_mut_range(start, end).clone_from_slice(replacement);
+        } else {
+            self.end -= end - start;
+            self.end += self.original.splice_range(start, end, replacement.iter().cloned());
+        }
+    }
+    
+    /// Sets the length of the slice to exactly `len`.
+    ///
+    /// If `len` is greater than the current length, the slice is extended with clones of `value`,
+    /// otherwise the elements past `len` are removed.
+    /// In both cases the rest of the vector is shifted only once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let mut slice = vec.vecslice(1..3);
+    ///
+    /// slice.ensure_len(4, 9);
+    /// assert_eq!(slice, [1, 2, 9, 9]);
+    /// assert_eq!(vec, [0, 1, 2, 9, 9, 3]);
+    ///
+    /// let mut slice = vec.vecslice(1..5);
+    /// slice.ensure_len(1, 9);
+    /// assert_eq!(slice, [1]);
+    /// assert_eq!(vec, [0, 1, 3]);
+    /// ```
+    pub fn ensure_len(&mut self, len: usize, value: T) where T: Clone {
+        // `resize` grows with a single `splice_range` and shrinks with a single `clear_range`
+        self.resize(len, value);
+    }
+
+    /// Resizes the slice in-place so that its length is equal to `new_len`.
+    ///
+    /// If `new_len` is greater than the current length, the slice is extended by the difference,
+    /// with each additional slot filled with `value`.
+    /// If `new_len` is less than the current length, the slice is simply truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let mut slice = vec.vecslice(1..3);
+    ///
+    /// slice.resize(4, 9);
+    /// assert_eq!(slice, [1, 2, 9, 9]);
+    /// slice.resize(1, 9);
+    /// assert_eq!(slice, [1]);
+    /// assert_eq!(vec, [0, 1, 3]);
+    /// ```
+    pub fn resize(&mut self, new_len: usize, value: T) where T: Clone {
+        if new_len > self.len() {
+            let additional = new_len - self.len();
+            self.end += self.original.splice_range(self.end, self.end, core::iter::repeat_n(value, additional));
+        } else {
+            self.truncate(new_len);
+        }
+    }
+
+    /// Resizes the slice in-place so that its length is equal to `new_len`.
+    ///
+    /// If `new_len` is greater than the current length, the slice is extended by the difference,
+    /// with each additional slot filled with the result of calling the closure `f`.
+    /// If `new_len` is less than the current length, the slice is simply truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let mut slice = vec.vecslice(1..3);
+    ///
+    /// let mut n = 4;
+    /// slice.resize_with(5, || { n += 1; n });
+    /// assert_eq!(slice, [1, 2, 5, 6, 7]);
+    /// assert_eq!(vec, [0, 1, 2, 5, 6, 7, 3]);
+    /// ```
+    pub fn resize_with<F: FnMut() -> T>(&mut self, new_len: usize, f: F) {
+        if new_len > self.len() {
+            let additional = new_len - self.len();
+            self.end += self.original.splice_range(self.end, self.end, core::iter::repeat_with(f).take(additional));
+        } else {
+            self.truncate(new_len);
+        }
+    }
+
+    /// Returns a reference to the element at position `index` within the slice, or [`None`] if it is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let slice = vec.vecslice(1..3);
+    /// assert_eq!(slice.get(1), Some(&2));
+    /// assert_eq!(slice.get(2), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    /// Returns a mutable reference to the element at position `index` within the slice, or [`None`] if it is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let mut slice = vec.vecslice(1..3);
+    /// if let Some(x) = slice.get_mut(1) {
+    ///     *x = 4;
+    /// }
+    /// assert_eq!(slice.get_mut(2), None);
+    /// assert_eq!(vec, [0, 1, 4, 3]);
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len() {
+            return None;
+        }
+        let index = self.start + index;
+        self.original.as_mut_range(index, index + 1).first_mut()
+    }
+
+    /// Returns the first element of the slice, or [`None`] if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2];
+    /// assert_eq!(vec.vecslice(1..).first(), Some(&1));
+    /// assert_eq!(vec.vecslice(1..1).first(), None);
+    /// ```
+    pub fn first(&self) -> Option<&T> {
+        self.as_slice().first()
+    }
+
+    /// Returns a mutable reference to the first element of the slice, or [`None`] if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2];
+    /// if let Some(first) = vec.vecslice(1..).first_mut() {
+    ///     *first = 5;
+    /// }
+    /// assert_eq!(vec, [0, 5, 2]);
+    /// ```
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    /// Returns the last element of the slice, or [`None`] if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2];
+    /// assert_eq!(vec.vecslice(..2).last(), Some(&1));
+    /// assert_eq!(vec.vecslice(1..1).last(), None);
+    /// ```
+    pub fn last(&self) -> Option<&T> {
+        self.as_slice().last()
+    }
+
+    /// Returns a mutable reference to the last element of the slice, or [`None`] if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2];
+    /// if let Some(last) = vec.vecslice(..2).last_mut() {
+    ///     *last = 5;
+    /// }
+    /// assert_eq!(vec, [0, 5, 2]);
+    /// ```
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.len().checked_sub(1)?)
+    }
+
+    /// Returns `true` if the slice contains an element with the given value.
+    ///
+    /// Elements outside the slice are not considered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let slice = vec.vecslice(1..3);
+    /// assert!(slice.contains(&2));
+    /// assert!(!slice.contains(&3));
+    /// ```
+    pub fn contains(&self, x: &T) -> bool where T: PartialEq {
+        self.as_slice().contains(x)
+    }
+
+    /// Returns `true` if `needle` is a prefix of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let slice = vec.vecslice(1..3);
+    /// assert!(slice.starts_with(&[1]));
+    /// assert!(slice.starts_with(&[]));
+    /// assert!(!slice.starts_with(&[0, 1]));
+    /// ```
+    pub fn starts_with(&self, needle: &[T]) -> bool where T: PartialEq {
+        self.as_slice().starts_with(needle)
+    }
+
+    /// Returns `true` if `needle` is a suffix of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let slice = vec.vecslice(1..3);
+    /// assert!(slice.ends_with(&[1, 2]));
+    /// assert!(slice.ends_with(&[]));
+    /// assert!(!slice.ends_with(&[2, 3]));
+    /// ```
+    pub fn ends_with(&self, needle: &[T]) -> bool where T: PartialEq {
+        self.as_slice().ends_with(needle)
+    }
+
+    /// Copies `self` into a new `Vec`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    /// 
+    /// let mut v = vec![1, 2, 3];
+    /// let slice = v.vecslice(0..2);
+    /// let s = slice.to_vec();
+    /// assert_eq!(s, [1, 2]);
+    /// assert_eq!(v, [1, 2, 3]);
+    /// // Here, `s` and `v` can be modified independently.
+    /// ```
+    pub fn to_vec(&self) -> Vec<T> where T: Clone {
+        self.as_slice().to_vec()
+    }
+    
+    /// Consumes `self` into a new `Vec`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    /// 
+    /// let mut vec = vec![1, 2, 3];
+    /// let slice = vec.vecslice(0..2);
+    /// let s = slice.to_vec();
     /// assert_eq!(s, [1, 2]);
     /// assert_eq!(vec, [1, 2, 3]);
     /// // Here, `s` and `v` can be modified independently.
     /// ```
-    pub fn into_vec(self) -> Vec<T> where T: Clone {
-        self.vec[self.start..self.end].to_vec()
+    pub fn into_vec(self) -> Vec<T> where T: Clone {
+        self.as_slice().to_vec()
+    }
+    
+    /// Returns a reference to the underlying slice.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use vecslice::Slice;
+    /// 
+    /// let mut vec = vec![1, 2, 3];
+    /// let slice = vec.vecslice(0..2);
+    /// assert_eq!(slice, [1, 2]);
+    /// assert_eq!(slice.as_slice(), [1, 2]);
+    pub fn as_slice(&self) -> &[T] {
+        &self.original.as_slice()[self.start..self.end]
+    }
+
+    /// Returns a mutable reference to the underlying slice.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use vecslice::Slice;
+    /// 
+    /// let mut vec = vec![1, 2, 3];
+    /// let mut slice = vec.vecslice(0..2);
+    /// assert_eq!(slice, [1, 2]);
+    /// let mut mut_slice = slice.as_mut_slice();
+    /// assert_eq!(mut_slice, [1, 2]);
+    /// mut_slice[0] = 4;
+    /// assert_eq!(mut_slice, [4, 2]);
+    /// assert_eq!(slice, [4, 2]);
+    /// assert_eq!(vec, [4, 2, 3]);
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.original.as_mut_range(self.start, self.end)
+    }
+
+    /// Returns a read-only [`VecSliceRef`] over the same window, which can be copied freely.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let slice = vec.vecslice(1..3);
+    /// let (a, b) = (slice.as_vecslice_ref(), slice.as_vecslice_ref());
+    /// assert_eq!(a, b);
+    /// assert_eq!(a, slice);
+    /// ```
+    pub fn as_vecslice_ref(&self) -> VecSliceRef<'_, T, S> {
+        VecSliceRef::new(self.start..self.end, &*self.original)
+    }
+
+    /// Returns a view over every `step`-th element of the slice, starting with the first one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5, 6];
+    /// let mut slice = vec.vecslice(1..);
+    ///
+    /// for x in &mut slice.strided(3) {
+    ///     *x *= 10;
+    /// }
+    /// assert_eq!(slice, [10, 2, 3, 40, 5, 6]);
+    /// ```
+    pub fn strided(&mut self, step: usize) -> StridedSlice<'_, T> {
+        StridedSlice::new(self.as_mut_slice(), step)
+    }
+
+    /// Converts the slice into a view over every `step`-th element of it, starting with the first one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    pub fn into_strided(self, step: usize) -> StridedSlice<'a, T> {
+        StridedSlice::new(self.original.as_mut_range(self.start, self.end), step)
+    }
+    
+    /// Sorts the slice.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and *O*(*n* \* log(*n*)) worst-case.
+    ///
+    /// When applicable, unstable sorting is preferred because it is generally faster than stable
+    /// sorting and it doesn't allocate auxiliary memory.
+    /// See [`sort_unstable`](slice::sort_unstable).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    /// 
+    /// let mut vec = vec![-5, 4, -3, 2, 1];
+    /// let mut slice = vec.vecslice(1..=3); 
+    ///
+    /// slice.sort();
+    /// assert_eq!(slice, [-3, 2, 4]);
+    /// assert_eq!(vec, [-5, -3, 2, 4, 1]);
+    /// ```
+    pub fn sort(&mut self) where T: Ord {
+        self.as_mut_slice().sort();
+    }
+
+    /// Sorts the slice with a comparator function.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and *O*(*n* \* log(*n*)) worst-case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    /// 
+    /// let mut vec = vec![-5, 4, -3, 2, 1];
+    /// let mut slice = vec.vecslice(1..=3);
+    ///
+    /// slice.sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(slice, [4, 2, -3]);
+    /// assert_eq!(vec, [-5, 4, 2, -3, 1]);
+    /// ```
+    pub fn sort_by<F: FnMut(&T, &T) -> core::cmp::Ordering>(&mut self, compare: F) {
+        self.as_mut_slice().sort_by(compare);
+    }
+
+    /// Sorts the slice with a key extraction function.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and *O*(*m* \* *n* \* log(*n*))
+    /// worst-case, where the key function is *O*(*m*).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    /// 
+    /// let mut vec = vec![-5, 4, -3, 2, 1];
+    /// let mut slice = vec.vecslice(1..=3);
+    ///
+    /// slice.sort_by_key(|k: &i32| k.abs());
+    /// assert_eq!(slice, [2, -3, 4]);
+    /// assert_eq!(vec, [-5, 2, -3, 4, 1]);
+    /// ```
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, f: F) {
+        self.as_mut_slice().sort_by_key(f);
+    }
+
+    /// Sorts the slice with a key extraction function, calling it at most once per element.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and *O*(*m* \* *n* + *n* \* log(*n*))
+    /// worst-case, where the key function is *O*(*m*).
+    /// It is faster than [`VecSlice::sort_by_key`] when the key function is expensive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    /// 
+    /// let mut vec = vec![-5, 4, 32, -3, 2];
+    /// let mut slice = vec.vecslice(1..);
+    ///
+    /// slice.sort_by_cached_key(|k| k.to_string());
+    /// assert_eq!(slice, [-3, 2, 32, 4]);
+    /// assert_eq!(vec, [-5, -3, 2, 32, 4]);
+    /// ```
+    pub fn sort_by_cached_key<K: Ord, F: FnMut(&T) -> K>(&mut self, f: F) {
+        self.as_mut_slice().sort_by_cached_key(f);
+    }
+    
+    /// Sorts the slice, but might not preserve the order of equal elements.
+    ///
+    /// This sort is unstable (i.e., may reorder equal elements), in-place
+    /// (i.e., does not allocate), and *O*(*n* \* log(*n*)) worst-case.
+    ///
+    /// It is typically faster than stable sorting, except in a few special cases, e.g., when the
+    /// slice consists of several concatenated sorted sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    /// 
+    /// let mut vec = vec![-5, 4, -3, 2, 1];
+    /// let mut slice = vec.vecslice(1..=3); 
+    ///
+    /// slice.sort_unstable();
+    /// assert_eq!(slice, [-3, 2, 4]);
+    /// assert_eq!(vec, [-5, -3, 2, 4, 1]);
+    /// ```
+    pub fn sort_unstable(&mut self) where T: Ord {
+        self.as_mut_slice().sort_unstable();
+    }
+
+    /// Sorts the slice with a comparator function, but might not preserve the order of equal elements.
+    ///
+    /// This sort is unstable (i.e., may reorder equal elements), in-place
+    /// (i.e., does not allocate), and *O*(*n* \* log(*n*)) worst-case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    /// 
+    /// let mut vec = vec![-5, 4, -3, 2, 1];
+    /// let mut slice = vec.vecslice(1..=3);
+    ///
+    /// slice.sort_unstable_by(|a, b| b.cmp(a));
+    /// assert_eq!(slice, [4, 2, -3]);
+    /// assert_eq!(vec, [-5, 4, 2, -3, 1]);
+    /// ```
+    pub fn sort_unstable_by<F: FnMut(&T, &T) -> core::cmp::Ordering>(&mut self, compare: F) {
+        self.as_mut_slice().sort_unstable_by(compare);
+    }
+
+    /// Sorts the slice with a key extraction function, but might not preserve the order of equal elements.
+    ///
+    /// This sort is unstable (i.e., may reorder equal elements), in-place
+    /// (i.e., does not allocate), and *O*(*m* \* *n* \* log(*n*)) worst-case, where the key function is *O*(*m*).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    /// 
+    /// let mut vec = vec![-5, 4, -3, 2, 1];
+    /// let mut slice = vec.vecslice(1..=3);
+    ///
+    /// slice.sort_unstable_by_key(|k: &i32| k.abs());
+    /// assert_eq!(slice, [2, -3, 4]);
+    /// assert_eq!(vec, [-5, 2, -3, 4, 1]);
+    /// ```
+    pub fn sort_unstable_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, f: F) {
+        self.as_mut_slice().sort_unstable_by_key(f);
+    }
+
+    /// Reorders the slice such that the element at `index` is at its final sorted position.
+    ///
+    /// Returns a triplet of the elements before `index`, the element at `index` and the elements after it,
+    /// where no element before `index` is greater than it and no element after it is less than it.
+    /// Only the elements inside the slice are reordered.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `index >= len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![9, -5, 4, 1, -3, 2, 0];
+    /// let mut slice = vec.vecslice(1..6);
+    ///
+    /// // Find the median
+    /// let (_, median, _) = slice.select_nth_unstable(2);
+    /// assert_eq!(*median, 1);
+    /// assert_eq!(vec[0], 9);
+    /// assert_eq!(vec[6], 0);
+    /// ```
+    pub fn select_nth_unstable(&mut self, index: usize) -> (&mut [T], &mut T, &mut [T]) where T: Ord {
+        self.as_mut_slice().select_nth_unstable(index)
+    }
+
+    /// Reorders the slice with a comparator function such that the element at `index` is at its final sorted position.
+    ///
+    /// See [`VecSlice::select_nth_unstable`] for the meaning of the returned value.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `index >= len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![9, -5, 4, 1, -3, 2, 0];
+    /// let mut slice = vec.vecslice(1..6);
+    ///
+    /// // Find the second largest element
+    /// let (_, second, _) = slice.select_nth_unstable_by(1, |a, b| b.cmp(a));
+    /// assert_eq!(*second, 2);
+    /// ```
+    pub fn select_nth_unstable_by<F: FnMut(&T, &T) -> core::cmp::Ordering>(&mut self, index: usize, compare: F) -> (&mut [T], &mut T, &mut [T]) {
+        self.as_mut_slice().select_nth_unstable_by(index, compare)
+    }
+
+    /// Reorders the slice with a key extraction function such that the element at `index` is at its final sorted position.
+    ///
+    /// See [`VecSlice::select_nth_unstable`] for the meaning of the returned value.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `index >= len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![9, -5, 4, 1, -3, 2, 0];
+    /// let mut slice = vec.vecslice(1..6);
+    ///
+    /// // Find the element closest to zero
+    /// let (_, closest, _) = slice.select_nth_unstable_by_key(0, |k: &i32| k.abs());
+    /// assert_eq!(*closest, 1);
+    /// ```
+    pub fn select_nth_unstable_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, index: usize, f: F) -> (&mut [T], &mut T, &mut [T]) {
+        self.as_mut_slice().select_nth_unstable_by_key(index, f)
+    }
+
+    /// Rotates the slice in-place such that the first `mid` elements of the slice move to the end
+    /// while the last `len - mid` elements move to the front.
+    ///
+    /// The elements outside the slice are not moved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5];
+    /// let mut slice = vec.vecslice(1..5);
+    ///
+    /// slice.rotate_left(1);
+    /// assert_eq!(slice, [2, 3, 4, 1]);
+    /// assert_eq!(vec, [0, 2, 3, 4, 1, 5]);
+    /// ```
+    pub fn rotate_left(&mut self, mid: usize) {
+        assert!(mid <= self.len(), "rotate_left mid (is {mid}) should be <= len (is {})", self.len());
+        self.as_mut_slice().rotate_left(mid);
+    }
+
+    /// Rotates the slice in-place such that the first `len - k` elements of the slice move to the end
+    /// while the last `k` elements move to the front.
+    ///
+    /// The elements outside the slice are not moved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5];
+    /// let mut slice = vec.vecslice(1..5);
+    ///
+    /// slice.rotate_right(1);
+    /// assert_eq!(slice, [4, 1, 2, 3]);
+    /// assert_eq!(vec, [0, 4, 1, 2, 3, 5]);
+    /// ```
+    pub fn rotate_right(&mut self, k: usize) {
+        assert!(k <= self.len(), "rotate_right k (is {k}) should be <= len (is {})", self.len());
+        self.as_mut_slice().rotate_right(k);
+    }
+
+    /// Reverses the order of the elements in the slice, in place.
+    ///
+    /// The elements outside the slice are not moved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4];
+    /// let mut slice = vec.vecslice(1..4);
+    ///
+    /// slice.reverse();
+    /// assert_eq!(slice, [3, 2, 1]);
+    /// assert_eq!(vec, [0, 3, 2, 1, 4]);
+    /// ```
+    pub fn reverse(&mut self) {
+        self.as_mut_slice().reverse();
+    }
+
+    /// Returns a view of the slice where index `0` is its last element, without moving any element.
+    ///
+    /// Every operation on the view is mirrored onto the slice, so [`Reversed::push_back`] prepends to the slice.
+    /// Use [`VecSlice::reverse`] to reverse the elements in place instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4];
+    /// let mut slice = vec.vecslice(1..4);
+    ///
+    /// let mut rev = slice.reversed();
+    /// assert_eq!(rev.pop_front(), Some(3));
+    /// rev.push_back(5);
+    /// assert_eq!(rev, [2, 1, 5]);
+    /// assert_eq!(vec, [0, 5, 1, 2, 4]);
+    /// ```
+    pub fn reversed(&mut self) -> Reversed<'_, 'a, T, S> {
+        Reversed::new(self)
+    }
+
+    /// Swaps two elements in the slice.
+    ///
+    /// If `a` equals to `b`, it's guaranteed that elements won't change value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4];
+    /// let mut slice = vec.vecslice(1..4);
+    ///
+    /// slice.swap(0, 2);
+    /// assert_eq!(slice, [3, 2, 1]);
+    /// assert_eq!(vec, [0, 3, 2, 1, 4]);
+    /// ```
+    pub fn swap(&mut self, a: usize, b: usize) {
+        let len = self.len();
+        assert!(a < len, "swap index a (is {a}) should be < len (is {len})");
+        assert!(b < len, "swap index b (is {b}) should be < len (is {len})");
+        self.as_mut_slice().swap(a, b);
+    }
+
+    /// Swaps all elements in `self` with those in `other`.
+    ///
+    /// The length of `other` must be the same as `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let mut slice = vec.vecslice(1..3);
+    /// let mut other = [5, 6];
+    ///
+    /// slice.swap_with_slice(&mut other);
+    /// assert_eq!(slice, [5, 6]);
+    /// assert_eq!(other, [1, 2]);
+    /// assert_eq!(vec, [0, 5, 6, 3]);
+    /// ```
+    pub fn swap_with_slice(&mut self, other: &mut [T]) {
+        self.as_mut_slice().swap_with_slice(other);
+    }
+
+    /// Binary searches this slice for a given element.
+    ///
+    /// If the value is found then [`Result::Ok`] is returned, containing the index of the matching element.
+    /// If there are multiple matches, then any one of the matches could be returned.
+    /// If the value is not found then [`Result::Err`] is returned, containing the index where a matching
+    /// element could be inserted while maintaining sorted order.
+    ///
+    /// All indices are relative to the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![9, 1, 3, 5, 0];
+    /// let slice = vec.vecslice(1..4);
+    ///
+    /// assert_eq!(slice.binary_search(&3), Ok(1));
+    /// assert_eq!(slice.binary_search(&4), Err(2));
+    /// assert_eq!(slice.binary_search(&9), Err(3));
+    /// ```
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize> where T: Ord {
+        self.as_slice().binary_search(x)
+    }
+
+    /// Binary searches this slice with a comparator function.
+    ///
+    /// The comparator function should return an order code that indicates whether its argument is
+    /// `Less`, `Equal` or `Greater` than the desired target.
+    /// See [`VecSlice::binary_search`] for the meaning of the returned value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![9, 1, 3, 5, 0];
+    /// let slice = vec.vecslice(1..4);
+    ///
+    /// assert_eq!(slice.binary_search_by(|x| x.cmp(&5)), Ok(2));
+    /// assert_eq!(slice.binary_search_by(|x| x.cmp(&0)), Err(0));
+    /// ```
+    pub fn binary_search_by<F: FnMut(&T) -> core::cmp::Ordering>(&self, f: F) -> Result<usize, usize> {
+        self.as_slice().binary_search_by(f)
+    }
+
+    /// Binary searches this slice with a key extraction function.
+    ///
+    /// Assumes that the slice is sorted by the key.
+    /// See [`VecSlice::binary_search`] for the meaning of the returned value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![(0, 'a'), (1, 'b'), (2, 'c'), (4, 'd')];
+    /// let slice = vec.vecslice(1..);
+    ///
+    /// assert_eq!(slice.binary_search_by_key(&2, |&(n, _)| n), Ok(1));
+    /// assert_eq!(slice.binary_search_by_key(&3, |&(n, _)| n), Err(2));
+    /// ```
+    pub fn binary_search_by_key<B: Ord, F: FnMut(&T) -> B>(&self, b: &B, f: F) -> Result<usize, usize> {
+        self.as_slice().binary_search_by_key(b, f)
+    }
+
+    /// Returns the index of the partition point according to the given predicate (the index of the first element of the second partition).
+    ///
+    /// The slice is assumed to be partitioned according to the given predicate:
+    /// all elements for which the predicate returns `true` are at the start of the slice
+    /// and all elements for which it returns `false` are at the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![9, 1, 2, 3, 5, 6, 7, 0];
+    /// let slice = vec.vecslice(1..7);
+    ///
+    /// assert_eq!(slice.partition_point(|&x| x < 5), 3);
+    /// ```
+    pub fn partition_point<P: FnMut(&T) -> bool>(&self, pred: P) -> usize {
+        self.as_slice().partition_point(pred)
+    }
+
+    /// Inserts `value` into the sorted slice, keeping it sorted, and returns its index within the slice.
+    ///
+    /// The slice must already be sorted, otherwise the position of `value` is unspecified.
+    /// If the slice contains elements equal to `value`, it is inserted after them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![9, 1, 3, 5, 0];
+    /// let mut slice = vec.vecslice(1..4);
+    ///
+    /// assert_eq!(slice.insert_sorted(4), 2);
+    /// assert_eq!(slice.insert_sorted(0), 0);
+    /// assert_eq!(slice, [0, 1, 3, 4, 5]);
+    /// assert_eq!(vec, [9, 0, 1, 3, 4, 5, 0]);
+    /// ```
+    pub fn insert_sorted(&mut self, value: T) -> usize where T: Ord {
+        self.insert_sorted_by(value, T::cmp)
     }
-    
-    /// Returns a reference to the underlying slice.
-    /// 
+
+    /// Inserts `value` into the slice sorted by the comparator function `compare`, keeping it sorted,
+    /// and returns its index within the slice.
+    ///
+    /// If the slice contains elements equal to `value`, it is inserted after them.
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use vecslice::Slice;
-    /// 
-    /// let mut vec = vec![1, 2, 3];
-    /// let slice = vec.vecslice(0..2);
-    /// assert_eq!(slice, [1, 2]);
-    /// assert_eq!(slice.as_slice(), [1, 2]);
-    pub fn as_slice(&self) -> &[T] {
-        &self.vec[self.start..self.end]
+    ///
+    /// let mut vec = vec![5, 3, 1];
+    /// let mut slice = vec.vecslice(..);
+    ///
+    /// assert_eq!(slice.insert_sorted_by(4, |a, b| b.cmp(a)), 1);
+    /// assert_eq!(vec, [5, 4, 3, 1]);
+    /// ```
+    pub fn insert_sorted_by<F: FnMut(&T, &T) -> core::cmp::Ordering>(&mut self, value: T, mut compare: F) -> usize {
+        let index = self.partition_point(|x| compare(x, &value).is_le());
+        self.insert(index, value);
+        index
     }
 
-    /// Returns a mutable reference to the underlying slice.
-    /// 
+    /// Inserts `value` into the slice sorted by the key extraction function `f`, keeping it sorted,
+    /// and returns its index within the slice.
+    ///
+    /// If the slice contains elements with a key equal to the one of `value`, it is inserted after them.
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use vecslice::Slice;
-    /// 
-    /// let mut vec = vec![1, 2, 3];
-    /// let mut slice = vec.vecslice(0..2);
-    /// assert_eq!(slice, [1, 2]);
-    /// let mut mut_slice = slice.as_mut_slice();
-    /// assert_eq!(mut_slice, [1, 2]);
-    /// mut_slice[0] = 4;
-    /// assert_eq!(mut_slice, [4, 2]);
-    /// assert_eq!(slice, [4, 2]);
-    /// assert_eq!(vec, [4, 2, 3]);
-    pub fn as_mut_slice(&mut self) -> &mut [T] {
-        &mut self.vec[self.start..self.end]
+    ///
+    /// let mut vec = vec![(0, 'a'), (1, 'b'), (3, 'c')];
+    /// let mut slice = vec.vecslice(1..);
+    ///
+    /// assert_eq!(slice.insert_sorted_by_key((1, 'd'), |&(n, _)| n), 1);
+    /// assert_eq!(vec, [(0, 'a'), (1, 'b'), (1, 'd'), (3, 'c')]);
+    /// ```
+    pub fn insert_sorted_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, value: T, mut f: F) -> usize {
+        let key = f(&value);
+        let index = self.partition_point(|x| f(x) <= key);
+        self.insert(index, value);
+        index
     }
-    
-    /// Sorts the slice.
+
+    /// Merges the sorted `other` into the slice, keeping it sorted.
     ///
-    /// This sort is stable (i.e., does not reorder equal elements) and *O*(*n* \* log(*n*)) worst-case.
+    /// The slice must already be sorted, otherwise the resulting order is unspecified.
+    /// Equal elements of the slice are kept before those of `other`.
     ///
-    /// When applicable, unstable sorting is preferred because it is generally faster than stable
-    /// sorting and it doesn't allocate auxiliary memory.
-    /// See [`sort_unstable`](slice::sort_unstable).
+    /// The merge is built in a separate buffer that replaces the slice in a single splice, so the rest of the vector is shifted only once
+    /// and it is *O*(*n* + *m*) instead of inserting every element of `other` on its own.
     ///
     /// # Examples
     ///
     /// ```
     /// use vecslice::Slice;
-    /// 
-    /// let mut vec = vec![-5, 4, -3, 2, 1];
-    /// let mut slice = vec.vecslice(1..=3); 
     ///
-    /// slice.sort();
-    /// assert_eq!(slice, [-3, 2, 4]);
-    /// assert_eq!(vec, [-5, -3, 2, 4, 1]);
+    /// let mut vec = vec![9, 1, 3, 5, 0];
+    /// let mut slice = vec.vecslice(1..4);
+    ///
+    /// slice.merge_sorted_with(&[2, 3, 6]);
+    /// assert_eq!(slice, [1, 2, 3, 3, 5, 6]);
+    /// assert_eq!(vec, [9, 1, 2, 3, 3, 5, 6, 0]);
     /// ```
-    pub fn sort(&mut self) where T: Ord {
-        self.vec[self.start..self.end].sort();
+    pub fn merge_sorted_with(&mut self, other: &[T]) where T: Ord + Clone {
+        self.merge_sorted(other);
     }
-    
-    /// Sorts the slice, but might not preserve the order of equal elements.
+
+    /// Inserts all of `values` into the sorted slice, keeping it sorted.
     ///
-    /// This sort is unstable (i.e., may reorder equal elements), in-place
-    /// (i.e., does not allocate), and *O*(*n* \* log(*n*)) worst-case.
+    /// `values` don't need to be sorted. Instead of calling [`VecSlice::insert_sorted`] for every value,
+    /// all of them are merged at once and the rest of the vector is shifted a single time.
     ///
-    /// It is typically faster than stable sorting, except in a few special cases, e.g., when the
-    /// slice consists of several concatenated sorted sequences.
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![9, 1, 3, 5, 0];
+    /// let mut slice = vec.vecslice(1..4);
+    ///
+    /// slice.insert_sorted_all(&[6, 0, 4]);
+    /// assert_eq!(slice, [0, 1, 3, 4, 5, 6]);
+    /// assert_eq!(vec, [9, 0, 1, 3, 4, 5, 6, 0]);
+    /// ```
+    pub fn insert_sorted_all(&mut self, values: &[T]) where T: Ord + Clone {
+        let mut values = values.to_vec();
+        values.sort();
+        self.merge_sorted(&values);
+    }
+
+    /// Merges the sorted `other` into the sorted slice, replacing it with the result in a single splice.
+    fn merge_sorted(&mut self, other: &[T]) where T: Ord + Clone {
+        let mut merged = Vec::with_capacity(self.len() + other.len());
+        let mut left = self.as_slice().iter().peekable();
+        let mut right = other.iter().peekable();
+        loop {
+            let take_left = match (left.peek(), right.peek()) {
+                (Some(l), Some(r)) => l <= r,
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (None, None) => break,
+            };
+            if take_left {
+                merged.extend(left.next().cloned());
+            } else {
+                merged.extend(right.next().cloned());
+            }
+        }
+
+        let inserted = self.original.splice_range(self.start, self.end, merged);
+        self.end = self.start + inserted;
+    }
+
+    /// Swaps two disjoint ranges of the slice.
+    ///
+    /// The ranges don't need to have the same length, if they differ the elements between them are shifted to make room.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ranges overlap or if any of them is out of the bounds of the slice.
     ///
     /// # Examples
     ///
     /// ```
     /// use vecslice::Slice;
-    /// 
-    /// let mut vec = vec![-5, 4, -3, 2, 1];
-    /// let mut slice = vec.vecslice(1..=3); 
     ///
-    /// slice.sort_unstable();
-    /// assert_eq!(slice, [-3, 2, 4]);
-    /// assert_eq!(vec, [-5, -3, 2, 4, 1]);
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5, 6, 7];
+    /// let mut slice = vec.vecslice(1..7);
+    ///
+    /// slice.swap_disjoint(0..2, 4..);
+    /// assert_eq!(slice, [5, 6, 3, 4, 1, 2]);
+    ///
+    /// slice.swap_disjoint(3..=4, ..1);
+    /// assert_eq!(slice, [4, 1, 6, 3, 5, 2]);
+    /// assert_eq!(vec, [0, 4, 1, 6, 3, 5, 2, 7]);
     /// ```
-    pub fn sort_unstable(&mut self) where T: Ord {
-        self.vec[self.start..self.end].sort_unstable();
+    pub fn swap_disjoint<R1: RangeBounds<usize>, R2: RangeBounds<usize>>(&mut self, a: R1, b: R2) {
+        let a = Self::translate_range(a, 0, self.len());
+        let b = Self::translate_range(b, 0, self.len());
+        let ((a_start, a_end), (b_start, b_end)) = if a.0 <= b.0 { (a, b) } else { (b, a) };
+        assert!(a_start <= a_end && b_start <= b_end && b_end <= self.len());
+        assert!(a_end <= b_start, "ranges overlap");
+
+        let slice = &mut self.as_mut_slice()[a_start..b_end];
+        let (a_len, b_len) = (a_end - a_start, b_end - b_start);
+        if a_len == b_len {
+            let (left, right) = slice.split_at_mut(b_start - a_start);
+            left[..a_len].swap_with_slice(right);
+        } else {
+            // [a, middle, b] -> [middle, b, a] -> [b, middle, a]
+            slice.rotate_left(a_len);
+            slice[..b_end - a_end].rotate_left(b_start - a_end);
+        }
+    }
+
+    /// Replaces every element of the slice, except the first, with its difference from the previous element.
+    ///
+    /// The first element is kept as the base, use [`VecSlice::decode_delta`] to restore the original values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 10, 12, 15, 15, 20];
+    /// let mut slice = vec.vecslice(1..5);
+    ///
+    /// slice.encode_delta();
+    /// assert_eq!(slice, [10, 2, 3, 0]);
+    /// assert_eq!(vec, [0, 10, 2, 3, 0, 20]);
+    /// ```
+    pub fn encode_delta(&mut self) where T: core::ops::Sub<Output = T> + Copy {
+        let slice = self.as_mut_slice();
+        for i in (1..slice.len()).rev() {
+            slice[i] = slice[i] - slice[i-1];
+        }
+    }
+
+    /// Replaces every element of the slice with the running sum up to it, reversing [`VecSlice::encode_delta`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 10, 2, 3, 0, 20];
+    /// let mut slice = vec.vecslice(1..5);
+    ///
+    /// slice.decode_delta();
+    /// assert_eq!(slice, [10, 12, 15, 15]);
+    /// assert_eq!(vec, [0, 10, 12, 15, 15, 20]);
+    /// ```
+    pub fn decode_delta(&mut self) where T: core::ops::Add<Output = T> + Copy {
+        let slice = self.as_mut_slice();
+        for i in 1..slice.len() {
+            slice[i] = slice[i] + slice[i-1];
+        }
+    }
+}
+
+impl<T, S: TrySliceable<T>> VecSlice<'_, T, S> {
+    /// Appends an element to the back of the slice, or returns it back in an error if the collection is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![1, 2];
+    /// let mut slice = vec.vecslice(..1);
+    /// assert_eq!(slice.try_push_back(3), Ok(()));
+    /// assert_eq!(vec, [1, 3, 2]);
+    /// ```
+    pub fn try_push_back(&mut self, element: T) -> Result<(), CapacityError<T>> {
+        self.original.try_insert(self.end, element)?;
+        self.end += 1;
+        Ok(())
+    }
+
+    /// Appends an element to the front of the slice, or returns it back in an error if the collection is full.
+    pub fn try_push_front(&mut self, element: T) -> Result<(), CapacityError<T>> {
+        self.original.try_insert(self.start, element)?;
+        self.end += 1;
+        Ok(())
     }
 }
 
-impl<T> Extend<T> for VecSlice<'_, T> {
+/// Space for the elements is reserved up front from the lower bound of the iterator's size hint.
+impl<T, S: Sliceable<T>> Extend<T> for VecSlice<'_, T, S> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-        self.vec.splice(self.end..self.end, iter);
+        let iter = iter.into_iter();
+        self.original.reserve(iter.size_hint().0);
+        self.end += self.original.splice_range(self.end, self.end, iter);
+    }
+}
+
+/// Copies the elements, so a slice can be extended from references without calling [`Iterator::copied`].
+///
+/// # Examples
+///
+/// ```
+/// use vecslice::Slice;
+///
+/// let mut vec = vec![1, 4];
+/// let mut slice = vec.vecslice(..1);
+/// slice.extend(&[2, 3]);
+/// assert_eq!(vec, [1, 2, 3, 4]);
+/// ```
+impl<'e, T: Copy + 'e, S: Sliceable<T>> Extend<&'e T> for VecSlice<'_, T, S> {
+    fn extend<I: IntoIterator<Item = &'e T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+/// Appends the UTF-8 encoding of the written text to the back of the slice.
+///
+/// # Examples
+///
+/// ```
+/// use std::fmt::Write;
+/// use vecslice::Slice;
+///
+/// let mut buffer = b"<>".to_vec();
+/// let mut slice = buffer.vecslice(1..1);
+/// write!(slice, "{}°", 21).unwrap();
+/// assert_eq!(buffer, "<21°>".as_bytes());
+/// ```
+impl<S: Sliceable<u8>> core::fmt::Write for VecSlice<'_, u8, S> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.end += self.original.splice_range(self.end, self.end, s.bytes());
+        Ok(())
+    }
+}
+
+/// Only the elements of the slice are printed, along with its bounds and the length of the underlying buffer,
+/// use [`VecSlice::debug_verbose`] to print the whole buffer.
+impl<T: std::fmt::Debug, S: Sliceable<T>> std::fmt::Debug for VecSlice<'_, T, S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VecSlice").field("slice", &self.as_slice()).field("start", &self.start).field("end", &self.end).field("original_len", &self.original.len()).finish()
     }
 }
 
-impl<T: std::fmt::Debug> std::fmt::Debug for VecSlice<'_, T> {
+/// Formats a [`VecSlice`] along with all the elements of its underlying buffer, created by [`VecSlice::debug_verbose`].
+struct VerboseDebug<'s, 'a, T, S: Sliceable<T>>(&'s VecSlice<'a, T, S>);
+
+impl<T: std::fmt::Debug, S: Sliceable<T>> std::fmt::Debug for VerboseDebug<'_, '_, T, S> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("VecSlice").field("slice", &&self.vec[self.start..self.end]).field("start", &self.start).field("end", &self.end).field("vec", &self.vec).finish()
+        let slice = self.0;
+        f.debug_struct("VecSlice").field("slice", &slice.as_slice()).field("start", &slice.start).field("end", &slice.end).field("original", &slice.original.as_slice()).finish()
+    }
+}
+
+impl<T: std::fmt::Debug, S: Sliceable<T>> VecSlice<'_, T, S> {
+    /// Returns a value that formats the slice like its [`Debug`](std::fmt::Debug) implementation,
+    /// but printing all the elements of the underlying buffer instead of only its length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![1, 2, 3, 4];
+    /// let slice = vec.vecslice(1..3);
+    /// assert_eq!(format!("{slice:?}"), "VecSlice { slice: [2, 3], start: 1, end: 3, original_len: 4 }");
+    /// assert_eq!(format!("{:?}", slice.debug_verbose()), "VecSlice { slice: [2, 3], start: 1, end: 3, original: [1, 2, 3, 4] }");
+    /// ```
+    pub fn debug_verbose(&self) -> impl std::fmt::Debug + '_ {
+        VerboseDebug(self)
     }
 }
 
-pub trait Slice<T> {
-    fn vecslice(&mut self, range: impl RangeBounds<usize>) -> VecSlice<T>;
+pub trait Slice<T>: Sliceable<T> + Sized {
+    fn vecslice(&mut self, range: impl RangeBounds<usize>) -> VecSlice<'_, T, Self>;
+    
+    fn vecslice_at_tail(&mut self) -> VecSlice<'_, T, Self>;
     
-    fn vecslice_at_tail(&mut self) -> VecSlice<T>;
+    fn vecslice_empty(&mut self) -> VecSlice<'_, T, Self>;
+    
+    fn try_vecslice(&mut self, range: impl RangeBounds<usize>) -> Result<VecSlice<'_, T, Self>, VecSliceError>;
+
+    fn vecslice_strided(&mut self, range: impl RangeBounds<usize>, step: usize) -> StridedSlice<'_, T>;
+
+    fn vecslice_ref(&self, range: impl RangeBounds<usize>) -> VecSliceRef<'_, T, Self>;
+
+    fn collect_vecslice_at_tail<I: IntoIterator<Item = T>>(&mut self, iter: I) -> VecSlice<'_, T, Self>;
 }
 
-impl<T> Slice<T> for Vec<T> {
-    /// Creates a new [`VecSlice`] of the [`Vec`] on the specified range.
-    fn vecslice(&mut self, range: impl RangeBounds<usize>) -> VecSlice<T> {
+impl<T, S: Sliceable<T>> Slice<T> for S {
+    /// Creates a new [`VecSlice`] of the collection on the specified range.
+    fn vecslice(&mut self, range: impl RangeBounds<usize>) -> VecSlice<'_, T, S> {
         VecSlice::new(range, self)
     }
     
-    /// Creates a new [`VecSlice`] at the tail of the [`Vec`].
+    /// Creates a new [`VecSlice`] at the tail of the collection.
     /// 
-    /// The new slice will be empty, and newly added elements will be appended to the end of the [`Vec`].
-    fn vecslice_at_tail(&mut self) -> VecSlice<T> {
+    /// The new slice will be empty, and newly added elements will be appended to the end of the collection.
+    fn vecslice_at_tail(&mut self) -> VecSlice<'_, T, S> {
         self.vecslice(self.len()..)
     }
+    
+    /// Creates a new empty [`VecSlice`] at the start of the collection.
+    /// 
+    /// Equivalent to `vecslice(0..0)`, newly added elements will be inserted at the front of the collection.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use vecslice::Slice;
+    /// 
+    /// let mut vec = vec![1, 2, 3];
+    /// let mut slice = vec.vecslice_empty();
+    /// assert!(slice.is_empty());
+    /// 
+    /// slice.push_back(4);
+    /// slice.push_back(5);
+    /// assert_eq!(slice, [4, 5]);
+    /// assert_eq!(vec, [4, 5, 1, 2, 3]);
+    /// ```
+    fn vecslice_empty(&mut self) -> VecSlice<'_, T, S> {
+        self.vecslice(0..0)
+    }
+    
+    /// Creates a new [`VecSlice`] of the collection on the specified range, or returns an error if the range is out of bounds.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use vecslice::{Slice, VecSliceError};
+    /// 
+    /// let mut vec = vec![1, 2, 3];
+    /// assert_eq!(vec.try_vecslice(..2).unwrap(), [1, 2]);
+    /// assert_eq!(vec.try_vecslice(4..).unwrap_err(), VecSliceError::StartOutOfBounds { start: 4, len: 3 });
+    /// ```
+    fn try_vecslice(&mut self, range: impl RangeBounds<usize>) -> Result<VecSlice<'_, T, S>, VecSliceError> {
+        VecSlice::try_new(range, self)
+    }
+
+    /// Creates a new [`StridedSlice`] over every `step`-th element of the collection on the specified range,
+    /// starting with the first one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero or if the range is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5, 6];
+    /// let mut strided = vec.vecslice_strided(1..6, 2);
+    /// assert_eq!(strided, [1, 3, 5]);
+    ///
+    /// strided[1] = 9;
+    /// assert_eq!(vec, [0, 1, 2, 9, 4, 5, 6]);
+    /// ```
+    fn vecslice_strided(&mut self, range: impl RangeBounds<usize>, step: usize) -> StridedSlice<'_, T> {
+        self.vecslice(range).into_strided(step)
+    }
+
+    /// Creates a new read-only [`VecSliceRef`] of the collection on the specified range.
+    ///
+    /// Only a shared reference to the collection is needed, so many of them can be alive at the same time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let vec = vec![1, 2, 3, 4];
+    /// let (a, b) = (vec.vecslice_ref(..2), vec.vecslice_ref(1..));
+    /// assert_eq!(a, [1, 2]);
+    /// assert_eq!(b, [2, 3, 4]);
+    /// ```
+    fn vecslice_ref(&self, range: impl RangeBounds<usize>) -> VecSliceRef<'_, T, S> {
+        VecSliceRef::new(range, self)
+    }
+
+    /// Appends all the elements of `iter` to the end of the collection, returning a [`VecSlice`] containing them.
+    ///
+    /// Space for the elements is reserved up front from the lower bound of the iterator's size hint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![1, 2, 3];
+    /// let mut section = vec.collect_vecslice_at_tail((4..7).map(|x| x * 10));
+    /// assert_eq!(section, [40, 50, 60]);
+    ///
+    /// section.push_front(0);
+    /// assert_eq!(vec, [1, 2, 3, 0, 40, 50, 60]);
+    /// ```
+    fn collect_vecslice_at_tail<I: IntoIterator<Item = T>>(&mut self, iter: I) -> VecSlice<'_, T, S> {
+        let mut slice = self.vecslice_at_tail();
+        slice.extend(iter);
+        slice
+    }
 }
 
-impl<T: PartialEq> PartialEq for VecSlice<'_, T> {
+impl<T: PartialEq, S: Sliceable<T>> PartialEq for VecSlice<'_, T, S> {
     fn eq(&self, other: &Self) -> bool {
-        self.vec[self.start..self.end] == other.vec[other.start..other.end]
+        self.as_slice() == other.as_slice()
     }
 }
 
-impl<T: PartialOrd> PartialOrd for VecSlice<'_, T> {
+impl<T: PartialOrd, S: Sliceable<T>> PartialOrd for VecSlice<'_, T, S> {
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-        self.vec[self.start..self.end].partial_cmp(&other.vec[other.start..other.end])
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Eq, S: Sliceable<T>> Eq for VecSlice<'_, T, S> {}
+
+impl<T: Ord, S: Sliceable<T>> Ord for VecSlice<'_, T, S> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_slice().cmp(other.as_slice())
     }
 }
 
-impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for VecSlice<'_, T> {
+impl<T: PartialEq, S: Sliceable<T>, const N: usize> PartialEq<[T; N]> for VecSlice<'_, T, S> {
     fn eq(&self, other: &[T; N]) -> bool {
-        &self.vec[self.start..self.end] == other
+        self.as_slice() == other
     }
 }
 
-impl<T: PartialOrd, const N: usize> PartialOrd<[T; N]> for VecSlice<'_, T> {
+impl<T: PartialOrd, S: Sliceable<T>, const N: usize> PartialOrd<[T; N]> for VecSlice<'_, T, S> {
     fn partial_cmp(&self, other: &[T; N]) -> Option<core::cmp::Ordering> {
-        self.vec[self.start..self.end].partial_cmp(other)
+        self.as_slice().partial_cmp(other)
     }
 }
 
-impl<T: PartialEq> PartialEq<&[T]> for VecSlice<'_, T> {
+impl<T: PartialEq, S: Sliceable<T>> PartialEq<&[T]> for VecSlice<'_, T, S> {
     fn eq(&self, other: &&[T]) -> bool {
-        &self.vec[self.start..self.end] == *other
+        self.as_slice() == *other
     }
 }
 
-impl<T: PartialOrd> PartialOrd<&[T]> for VecSlice<'_, T> {
+impl<T: PartialOrd, S: Sliceable<T>> PartialOrd<&[T]> for VecSlice<'_, T, S> {
     fn partial_cmp(&self, other: &&[T]) -> Option<core::cmp::Ordering> {
-        self.vec[self.start..self.end].partial_cmp(other)
+        self.as_slice().partial_cmp(other)
     }
 }
 
-impl<T: PartialEq> PartialEq<Vec<T>> for VecSlice<'_, T> {
+#[cfg(not(feature = "allocator_api"))]
+impl<T: PartialEq, S: Sliceable<T>> PartialEq<Vec<T>> for VecSlice<'_, T, S> {
     fn eq(&self, other: &Vec<T>) -> bool {
-        &self.vec[self.start..self.end] == other
+        self.as_slice() == other
     }
 }
 
-impl<T: PartialOrd> PartialOrd<Vec<T>> for VecSlice<'_, T> {
+#[cfg(not(feature = "allocator_api"))]
+impl<T: PartialOrd, S: Sliceable<T>> PartialOrd<Vec<T>> for VecSlice<'_, T, S> {
     fn partial_cmp(&self, other: &Vec<T>) -> Option<core::cmp::Ordering> {
-        self.vec[self.start..self.end].partial_cmp(other)
+        self.as_slice().partial_cmp(other)
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<T: PartialEq, S: Sliceable<T>, A: std::alloc::Allocator> PartialEq<Vec<T, A>> for VecSlice<'_, T, S> {
+    fn eq(&self, other: &Vec<T, A>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<T: PartialOrd, S: Sliceable<T>, A: std::alloc::Allocator> PartialOrd<Vec<T, A>> for VecSlice<'_, T, S> {
+    fn partial_cmp(&self, other: &Vec<T, A>) -> Option<core::cmp::Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: PartialEq, S: Sliceable<T>, const N: usize> PartialEq<VecSlice<'_, T, S>> for [T; N] {
+    fn eq(&self, other: &VecSlice<'_, T, S>) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<T: PartialEq, S: Sliceable<T>> PartialEq<VecSlice<'_, T, S>> for [T] {
+    fn eq(&self, other: &VecSlice<'_, T, S>) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<T: PartialEq, S: Sliceable<T>> PartialEq<VecSlice<'_, T, S>> for &[T] {
+    fn eq(&self, other: &VecSlice<'_, T, S>) -> bool {
+        *self == other.as_slice()
+    }
+}
+
+#[cfg(not(feature = "allocator_api"))]
+impl<T: PartialEq, S: Sliceable<T>> PartialEq<VecSlice<'_, T, S>> for Vec<T> {
+    fn eq(&self, other: &VecSlice<'_, T, S>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<T: PartialEq, S: Sliceable<T>, A: std::alloc::Allocator> PartialEq<VecSlice<'_, T, S>> for Vec<T, A> {
+    fn eq(&self, other: &VecSlice<'_, T, S>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+/// Hashes the elements of the slice like `[T]` does, so it is consistent with the [`Borrow<[T]>`](core::borrow::Borrow) implementation.
+impl<T: core::hash::Hash, S: Sliceable<T>> core::hash::Hash for VecSlice<'_, T, S> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
     }
 }
 
-impl<T> core::borrow::Borrow<[T]> for VecSlice<'_, T> {
+impl<T, S: Sliceable<T>> core::borrow::Borrow<[T]> for VecSlice<'_, T, S> {
     fn borrow(&self) -> &[T] {
-        &self.vec[self.start..self.end]
+        self.as_slice()
     }
 }
 
-impl<T> core::borrow::BorrowMut<[T]> for VecSlice<'_, T> {
+impl<T, S: Sliceable<T>> core::borrow::BorrowMut<[T]> for VecSlice<'_, T, S> {
     fn borrow_mut(&mut self) -> &mut [T] {
-        &mut self.vec[self.start..self.end]
+        self.as_mut_slice()
     }
 }
 
-impl<T> AsRef<[T]> for VecSlice<'_, T> {
+impl<T, S: Sliceable<T>> AsRef<[T]> for VecSlice<'_, T, S> {
     fn as_ref(&self) -> &[T] {
-        &self.vec[self.start..self.end]
+        self.as_slice()
     }
 }
 
-impl<T> AsMut<[T]> for VecSlice<'_, T> {
+impl<T, S: Sliceable<T>> AsMut<[T]> for VecSlice<'_, T, S> {
     fn as_mut(&mut self) -> &mut [T] {
-        &mut self.vec[self.start..self.end]
+        self.as_mut_slice()
     }
 }
\ No newline at end of file
@@ -0,0 +1,247 @@
+use core::marker::PhantomData;
+use core::ops::{Range, RangeBounds};
+
+use crate::{Sliceable, VecSlice, VecSliceError};
+
+/// Growable window over a range of a collection that owns the collection.
+///
+/// Works like a [`VecSlice`] without the borrow, so it can be stored in structs and returned from functions.
+/// The full editing API is available through [`VecSliceOwned::vecslice`], and the collection can be recovered
+/// with [`VecSliceOwned::into_inner`].
+///
+/// # Examples
+///
+/// ```
+/// use vecslice::VecSliceOwned;
+///
+/// fn body(html: String) -> VecSliceOwned<u8> {
+///     let start = html.find("<body>").unwrap() + 6;
+///     let end = html.find("</body>").unwrap();
+///     VecSliceOwned::new(start..end, html.into_bytes())
+/// }
+///
+/// let mut body = body(String::from("<body>hi</body>"));
+/// body.vecslice().extend(*b" there");
+/// body.push_front(b'>');
+/// assert_eq!(body, *b">hi there");
+/// assert_eq!(body.into_inner(), b"<body>>hi there</body>");
+/// ```
+pub struct VecSliceOwned<T, S: Sliceable<T> = Vec<T>> {
+    start: usize,
+    /// Number of elements after the window, which is the only part of the collection a [`VecSlice`] can't change.
+    tail: usize,
+    original: S,
+    marker: PhantomData<T>,
+}
+
+impl<T, S: Sliceable<T>> VecSliceOwned<T, S> {
+    /// Creates a new [`VecSliceOwned`] of `original` on the specified range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn new(range: impl RangeBounds<usize>, mut original: S) -> VecSliceOwned<T, S> {
+        original.make_contiguous();
+        let (start, end) = VecSlice::<T, S>::translate_range(range, 0, original.len());
+        assert!(start <= end && end <= original.len(), "range {start}..{end} out of range for slice of length {}", original.len());
+        VecSliceOwned { start, tail: original.len() - end, original, marker: PhantomData }
+    }
+
+    /// Creates a new [`VecSliceOwned`] of `original` on the specified range, or returns an error if the range is out of bounds.
+    ///
+    /// The collection is returned back along with the error.
+    pub fn try_new(range: impl RangeBounds<usize>, mut original: S) -> Result<VecSliceOwned<T, S>, (VecSliceError, S)> {
+        original.make_contiguous();
+        match VecSlice::<T, S>::try_translate_range(range, original.len()) {
+            Ok((start, end)) => Ok(VecSliceOwned { start, tail: original.len() - end, original, marker: PhantomData }),
+            Err(err) => Err((err, original)),
+        }
+    }
+
+    /// Consumes the window, returning the collection.
+    pub fn into_inner(self) -> S {
+        self.original
+    }
+
+    /// Consumes the window, returning the collection and the range of the window in it.
+    pub fn into_parts(self) -> (S, Range<usize>) {
+        let range = self.range();
+        (self.original, range)
+    }
+
+    /// Returns a reference to the whole collection.
+    pub fn original(&self) -> &S {
+        &self.original
+    }
+
+    /// Returns the range of the window in the collection.
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end()
+    }
+
+    fn end(&self) -> usize {
+        self.original.len() - self.tail
+    }
+
+    /// Borrows the window as a [`VecSlice`], giving access to its whole API.
+    ///
+    /// Any change made through the [`VecSlice`] is kept in the window, which it can't grow past.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::VecSliceOwned;
+    ///
+    /// let mut owned = VecSliceOwned::new(1..3, vec![0, 1, 2, 3]);
+    /// owned.vecslice().insert_many(1, [4, 5]);
+    /// owned.vecslice().retain(|&x| x != 2);
+    /// assert_eq!(owned, [1, 4, 5]);
+    /// assert_eq!(owned.into_inner(), [0, 1, 4, 5, 3]);
+    /// ```
+    pub fn vecslice(&mut self) -> VecSlice<'_, T, S> {
+        let end = self.end();
+        VecSlice::lent(self.start, end, &mut self.original)
+    }
+
+    /// Returns the number of elements in the window.
+    pub fn len(&self) -> usize {
+        self.end() - self.start
+    }
+
+    /// Returns `true` if the window has a length of 0.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Extracts a slice containing the window.
+    pub fn as_slice(&self) -> &[T] {
+        &self.original.as_slice()[self.range()]
+    }
+
+    /// Extracts a mutable slice containing the window.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let end = self.end();
+        self.original.as_mut_range(self.start, end)
+    }
+
+    /// Returns an iterator over the window.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Returns an iterator over the window that allows modifying each value.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.as_mut_slice().iter_mut()
+    }
+
+    /// Appends an element to the back of the window.
+    pub fn push_back(&mut self, element: T) {
+        self.vecslice().push_back(element);
+    }
+
+    /// Appends an element to the front of the window.
+    pub fn push_front(&mut self, element: T) {
+        self.vecslice().push_front(element);
+    }
+
+    /// Removes the last element of the window and returns it, or [`None`] if it is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.vecslice().pop_back()
+    }
+
+    /// Removes the first element of the window and returns it, or [`None`] if it is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.vecslice().pop_front()
+    }
+
+    /// Inserts an element at position `index` within the window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, element: T) {
+        self.vecslice().insert(index, element);
+    }
+
+    /// Removes and returns the element at position `index` within the window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        self.vecslice().remove(index)
+    }
+
+    /// Removes all the elements of the window from the collection.
+    pub fn clear(&mut self) {
+        self.vecslice().clear();
+    }
+}
+
+impl<T, S: Sliceable<T>, I: core::slice::SliceIndex<[T]>> core::ops::Index<I> for VecSliceOwned<T, S> {
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        &self.as_slice()[index]
+    }
+}
+
+impl<T, S: Sliceable<T>, I: core::slice::SliceIndex<[T]>> core::ops::IndexMut<I> for VecSliceOwned<T, S> {
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.as_mut_slice()[index]
+    }
+}
+
+impl<T, S: Sliceable<T>> Extend<T> for VecSliceOwned<T, S> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.vecslice().extend(iter);
+    }
+}
+
+impl<T, S: Sliceable<T>> From<S> for VecSliceOwned<T, S> {
+    fn from(original: S) -> Self {
+        Self::new(.., original)
+    }
+}
+
+impl<T: core::fmt::Debug, S: Sliceable<T>> core::fmt::Debug for VecSliceOwned<T, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("VecSliceOwned").field("slice", &self.as_slice()).field("start", &self.start).field("end", &self.end()).field("original_len", &self.original.len()).finish()
+    }
+}
+
+impl<T: PartialEq, S: Sliceable<T>> PartialEq for VecSliceOwned<T, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq, S: Sliceable<T>, const N: usize> PartialEq<[T; N]> for VecSliceOwned<T, S> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq, S: Sliceable<T>> PartialEq<&[T]> for VecSliceOwned<T, S> {
+    fn eq(&self, other: &&[T]) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<T: PartialEq, S: Sliceable<T>> PartialEq<Vec<T>> for VecSliceOwned<T, S> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T, S: Sliceable<T>> AsRef<[T]> for VecSliceOwned<T, S> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, S: Sliceable<T>> AsMut<[T]> for VecSliceOwned<T, S> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
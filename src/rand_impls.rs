@@ -0,0 +1,98 @@
+//! [`rand`] support, behind the `rand` feature.
+
+use rand::seq::{IndexedMutRandom, IndexedRandom, SliceRandom};
+use rand::Rng;
+
+use crate::{Sliceable, VecSlice};
+
+impl<T, S: Sliceable<T>> VecSlice<'_, T, S> {
+    /// Shuffles the elements of the slice in place, leaving the rest of the buffer untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rand::SeedableRng;
+    /// use rand::rngs::SmallRng;
+    /// use vecslice::Slice;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(0);
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5];
+    /// let mut slice = vec.vecslice(1..5);
+    ///
+    /// slice.shuffle(&mut rng);
+    /// slice.sort();
+    /// assert_eq!(vec, [0, 1, 2, 3, 4, 5]);
+    /// ```
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.as_mut_slice().shuffle(rng);
+    }
+
+    /// Shuffles `amount` random elements of the slice into its end,
+    /// returning them and the remaining elements, like [`SliceRandom::partial_shuffle`].
+    ///
+    /// If `amount` is greater than the length of the slice, the whole slice is shuffled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rand::SeedableRng;
+    /// use rand::rngs::SmallRng;
+    /// use vecslice::Slice;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(0);
+    /// let mut vec = vec![0, 1, 2, 3, 4, 5];
+    /// let mut slice = vec.vecslice(1..5);
+    ///
+    /// let (sample, rest) = slice.partial_shuffle(&mut rng, 3);
+    /// assert_eq!(sample.len(), 3);
+    /// assert_eq!(rest.len(), 1);
+    /// ```
+    pub fn partial_shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R, amount: usize) -> (&mut [T], &mut [T]) {
+        self.as_mut_slice().partial_shuffle(rng, amount)
+    }
+
+    /// Returns a reference to a random element of the slice, or [`None`] if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rand::SeedableRng;
+    /// use rand::rngs::SmallRng;
+    /// use vecslice::Slice;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(0);
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let mut slice = vec.vecslice(1..3);
+    ///
+    /// assert!(matches!(slice.choose(&mut rng), Some(1 | 2)));
+    /// slice.clear();
+    /// assert_eq!(slice.choose(&mut rng), None);
+    /// ```
+    pub fn choose<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<&T> {
+        self.as_slice().choose(rng)
+    }
+
+    /// Returns a mutable reference to a random element of the slice, or [`None`] if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rand::SeedableRng;
+    /// use rand::rngs::SmallRng;
+    /// use vecslice::Slice;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(0);
+    /// let mut vec = vec![0, 1, 1, 0];
+    /// let mut slice = vec.vecslice(1..3);
+    ///
+    /// *slice.choose_mut(&mut rng).unwrap() = 2;
+    /// assert_eq!(vec.iter().sum::<i32>(), 3);
+    /// ```
+    pub fn choose_mut<R: Rng + ?Sized>(&mut self, rng: &mut R) -> Option<&mut T> {
+        // Avoid a mutable access to the buffer when there is nothing to choose
+        if self.is_empty() {
+            return None;
+        }
+        self.as_mut_slice().choose_mut(rng)
+    }
+}
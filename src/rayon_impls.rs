@@ -0,0 +1,37 @@
+//! [`rayon`] support, behind the `rayon` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use rayon::prelude::*;
+//! use vecslice::Slice;
+//!
+//! let mut vec: Vec<u64> = (0..1000).collect();
+//! let mut slice = vec.vecslice(500..);
+//!
+//! slice.par_iter_mut().for_each(|x| *x *= 2);
+//! assert_eq!(slice.par_iter().sum::<u64>(), 2 * (500..1000).sum::<u64>());
+//! assert_eq!(vec[499..502], [499, 1000, 1002]);
+//! ```
+
+use rayon::iter::IntoParallelIterator;
+
+use crate::{Sliceable, VecSlice};
+
+impl<'data, T: Sync + 'data, S: Sliceable<T>> IntoParallelIterator for &'data VecSlice<'_, T, S> {
+    type Item = &'data T;
+    type Iter = rayon::slice::Iter<'data, T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.as_slice().into_par_iter()
+    }
+}
+
+impl<'data, T: Send + 'data, S: Sliceable<T>> IntoParallelIterator for &'data mut VecSlice<'_, T, S> {
+    type Item = &'data mut T;
+    type Iter = rayon::slice::IterMut<'data, T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.as_mut_slice().into_par_iter()
+    }
+}
@@ -0,0 +1,218 @@
+use crate::{Sliceable, VecSlice};
+
+/// View over a [`VecSlice`] with the order of its elements reversed.
+///
+/// This `struct` is created by [`VecSlice::reversed`].
+///
+/// Index `0` of the view is the last element of the slice, and every operation is mirrored:
+/// [`Reversed::push_back`] prepends to the slice, [`Reversed::pop_front`] removes its last element, and so on.
+/// The elements are never moved to reverse them, so the view is free to create.
+///
+/// # Examples
+///
+/// ```
+/// use vecslice::Slice;
+///
+/// let mut vec = vec![0, 1, 2, 3];
+/// let mut slice = vec.vecslice(1..3);
+///
+/// // Build the output right-to-left
+/// let mut rev = slice.reversed();
+/// assert_eq!(rev, [2, 1]);
+/// rev.push_back(4);
+/// rev.push_back(5);
+/// assert_eq!(rev[0], 2);
+/// assert_eq!(rev, [2, 1, 4, 5]);
+///
+/// assert_eq!(slice, [5, 4, 1, 2]);
+/// assert_eq!(vec, [0, 5, 4, 1, 2, 3]);
+/// ```
+pub struct Reversed<'s, 'a, T, S: Sliceable<T> = Vec<T>> {
+    slice: &'s mut VecSlice<'a, T, S>,
+}
+
+impl<'s, 'a, T, S: Sliceable<T>> Reversed<'s, 'a, T, S> {
+    pub(crate) fn new(slice: &'s mut VecSlice<'a, T, S>) -> Self {
+        Reversed { slice }
+    }
+
+    /// Returns the underlying slice, in its original order.
+    pub fn into_inner(self) -> &'s mut VecSlice<'a, T, S> {
+        self.slice
+    }
+
+    /// Returns the number of elements in the view.
+    pub fn len(&self) -> usize {
+        self.slice.len()
+    }
+
+    /// Returns `true` if the view has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.slice.is_empty()
+    }
+
+    /// Translates a position in the view into one in the slice, for an element at `index`.
+    fn mirror(&self, index: usize) -> Option<usize> {
+        self.len().checked_sub(index.checked_add(1)?)
+    }
+
+    /// Appends an element to the back of the view, which is the front of the slice.
+    pub fn push_back(&mut self, element: T) {
+        self.slice.push_front(element);
+    }
+
+    /// Appends an element to the front of the view, which is the back of the slice.
+    pub fn push_front(&mut self, element: T) {
+        self.slice.push_back(element);
+    }
+
+    /// Removes the last element of the view, which is the first of the slice, and returns it, or [`None`] if it is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.slice.pop_front()
+    }
+
+    /// Removes the first element of the view, which is the last of the slice, and returns it, or [`None`] if it is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.slice.pop_back()
+    }
+
+    /// Inserts an element at position `index` within the view, so it ends up right after the element
+    /// at position `index - 1` of the view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2];
+    /// let mut slice = vec.vecslice(..);
+    /// let mut rev = slice.reversed();
+    /// rev.insert(1, 3);
+    /// assert_eq!(rev, [2, 3, 1, 0]);
+    /// assert_eq!(vec, [0, 1, 3, 2]);
+    /// ```
+    pub fn insert(&mut self, index: usize, element: T) {
+        let len = self.len();
+        assert!(index <= len, "insertion index (is {index}) should be <= len (is {len})");
+        self.slice.insert(len - index, element);
+    }
+
+    /// Removes and returns the element at position `index` within the view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        let Some(index) = self.mirror(index) else {
+            panic!("removal index (is {index}) should be < len (is {len})");
+        };
+        self.slice.remove(index)
+    }
+
+    /// Removes all the elements of the view from the buffer.
+    pub fn clear(&mut self) {
+        self.slice.clear();
+    }
+
+    /// Returns a reference to the element at position `index` within the view, or [`None`] if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.slice.get(self.mirror(index)?)
+    }
+
+    /// Returns a mutable reference to the element at position `index` within the view, or [`None`] if it is out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let index = self.mirror(index)?;
+        self.slice.get_mut(index)
+    }
+
+    /// Returns the first element of the view, which is the last of the slice, or [`None`] if it is empty.
+    pub fn first(&self) -> Option<&T> {
+        self.slice.last()
+    }
+
+    /// Returns the last element of the view, which is the first of the slice, or [`None`] if it is empty.
+    pub fn last(&self) -> Option<&T> {
+        self.slice.first()
+    }
+
+    /// Returns an iterator over the view, from the last element of the slice to the first.
+    pub fn iter(&self) -> core::iter::Rev<std::slice::Iter<'_, T>> {
+        self.slice.iter().rev()
+    }
+
+    /// Returns an iterator that allows modifying each value, from the last element of the slice to the first.
+    pub fn iter_mut(&mut self) -> core::iter::Rev<std::slice::IterMut<'_, T>> {
+        self.slice.iter_mut().rev()
+    }
+
+    /// Copies the elements of the view, in the order of the view, into a new `Vec`.
+    pub fn to_vec(&self) -> Vec<T> where T: Clone {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T, S: Sliceable<T>> core::ops::Index<usize> for Reversed<'_, '_, T, S> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        let len = self.len();
+        self.get(index).unwrap_or_else(|| panic!("index out of bounds: the len is {len} but the index is {index}"))
+    }
+}
+
+impl<T, S: Sliceable<T>> core::ops::IndexMut<usize> for Reversed<'_, '_, T, S> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len();
+        self.get_mut(index).unwrap_or_else(|| panic!("index out of bounds: the len is {len} but the index is {index}"))
+    }
+}
+
+impl<T, S: Sliceable<T>> Extend<T> for Reversed<'_, '_, T, S> {
+    /// Appends the elements to the back of the view, so they end up in reverse order at the front of the slice.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut elements: Vec<T> = iter.into_iter().collect();
+        elements.reverse();
+        self.slice.extend_front(elements);
+    }
+}
+
+impl<T: core::fmt::Debug, S: Sliceable<T>> core::fmt::Debug for Reversed<'_, '_, T, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, S: Sliceable<T>> PartialEq<[T]> for Reversed<'_, '_, T, S> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.len() == other.len() && self.iter().eq(other)
+    }
+}
+
+impl<T: PartialEq, S: Sliceable<T>, const N: usize> PartialEq<[T; N]> for Reversed<'_, '_, T, S> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<'r, T, S: Sliceable<T>> IntoIterator for &'r Reversed<'_, '_, T, S> {
+    type Item = &'r T;
+    type IntoIter = core::iter::Rev<std::slice::Iter<'r, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'r, T, S: Sliceable<T>> IntoIterator for &'r mut Reversed<'_, '_, T, S> {
+    type Item = &'r mut T;
+    type IntoIter = core::iter::Rev<std::slice::IterMut<'r, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
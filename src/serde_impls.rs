@@ -0,0 +1,67 @@
+//! [`serde`] support, behind the `serde` feature.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::{Sliceable, VecSlice};
+
+/// Serializes the elements of the slice as a sequence, ignoring the rest of the buffer.
+impl<T: Serialize, S: Sliceable<T>> Serialize for VecSlice<'_, T, S> {
+    fn serialize<Z: Serializer>(&self, serializer: Z) -> Result<Z::Ok, Z::Error> {
+        serializer.collect_seq(self.as_slice())
+    }
+}
+
+struct ElementsVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for ElementsVisitor<T> {
+    type Value = Vec<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        // Don't trust the size hint blindly, it comes from the input
+        let mut elements = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(element) = seq.next_element()? {
+            elements.push(element);
+        }
+        Ok(elements)
+    }
+}
+
+impl<T, S: Sliceable<T>> VecSlice<'_, T, S> {
+    /// Replaces the elements of the slice with a sequence read from `deserializer`,
+    /// resizing it if the sequence has a different length.
+    ///
+    /// Only the new elements are buffered, the rest of the underlying buffer is shifted once at most.
+    /// If deserialization fails, the slice is left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde::de::IntoDeserializer;
+    /// use serde::de::value::{Error, SeqDeserializer};
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let mut slice = vec.vecslice(1..3);
+    ///
+    /// let deserializer: SeqDeserializer<_, Error> = vec![4, 5, 6].into_deserializer();
+    /// slice.deserialize_in_place(deserializer).unwrap();
+    /// assert_eq!(slice, [4, 5, 6]);
+    /// assert_eq!(vec, [0, 4, 5, 6, 3]);
+    /// ```
+    pub fn deserialize_in_place<'de, D: Deserializer<'de>>(&mut self, deserializer: D) -> Result<(), D::Error>
+    where
+        T: Deserialize<'de>,
+    {
+        let elements = deserializer.deserialize_seq(ElementsVisitor(PhantomData))?;
+        self.end = self.start + self.original.splice_range(self.start, self.end, elements);
+        Ok(())
+    }
+}
@@ -0,0 +1,181 @@
+use core::marker::PhantomData;
+use core::ops::RangeBounds;
+
+use crate::{Sliceable, VecSlice};
+
+/// Grid view with `rows` rows of `cols` elements over a range of a flat buffer.
+///
+/// Rows are stored one after another, and can be borrowed as a [`VecSlice`] with [`VecSlice2D::row`],
+/// or added and removed as a whole with [`VecSlice2D::push_row`] and [`VecSlice2D::remove_row`].
+///
+/// # Examples
+///
+/// ```
+/// use vecslice::VecSlice2D;
+///
+/// let mut vec = vec![0, 1, 2, 3, 4, 5, 6, 7];
+/// let mut grid = VecSlice2D::new(1..7, &mut vec, 3);
+/// assert_eq!(grid.shape(), (2, 3));
+/// assert_eq!(grid[(1, 0)], 4);
+///
+/// grid[(0, 2)] = 9;
+/// grid.row(1).reverse();
+/// grid.push_row([7, 8, 9]);
+/// assert_eq!(grid.iter_rows().collect::<Vec<_>>(), [[1, 2, 9], [6, 5, 4], [7, 8, 9]]);
+/// assert_eq!(vec, [0, 1, 2, 9, 6, 5, 4, 7, 8, 9, 7]);
+/// ```
+pub struct VecSlice2D<'a, T, S: Sliceable<T> = Vec<T>> {
+    start: usize,
+    rows: usize,
+    cols: usize,
+    /// Length of `original` the last time a row was borrowed, used to detect rows that changed their length.
+    len: usize,
+    original: &'a mut S,
+    marker: PhantomData<T>,
+}
+
+impl<'a, T, S: Sliceable<T>> VecSlice2D<'a, T, S> {
+    /// Creates a new [`VecSlice2D`] with rows of `cols` elements over the specified range of `original`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cols` is zero, or if the length of the range is not a multiple of `cols`.
+    pub fn new(range: impl RangeBounds<usize>, original: &'a mut S, cols: usize) -> VecSlice2D<'a, T, S> {
+        assert!(cols != 0, "number of columns must be non-zero");
+        original.make_contiguous();
+        let (start, end) = VecSlice::<T, S>::translate_range(range, 0, original.len());
+        assert!(start <= end && end <= original.len());
+        assert!((end - start) % cols == 0, "length of the range (is {}) should be a multiple of cols (is {cols})", end - start);
+        let len = original.len();
+        VecSlice2D { start, rows: (end - start) / cols, cols, len, original, marker: PhantomData }
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the number of rows and columns.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns a slice containing every element of the grid, row after row.
+    pub fn as_slice(&self) -> &[T] {
+        self.check();
+        &self.original.as_slice()[self.start..self.start + self.rows * self.cols]
+    }
+
+    /// Returns a mutable slice containing every element of the grid, row after row.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.check();
+        self.original.as_mut_range(self.start, self.start + self.rows * self.cols)
+    }
+
+    /// Returns a reference to the element at row `row` and column `col`, or [`None`] if it is out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.rows && col < self.cols {
+            Some(&self.as_slice()[row * self.cols + col])
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the element at row `row` and column `col`, or [`None`] if it is out of bounds.
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row < self.rows && col < self.cols {
+            let cols = self.cols;
+            Some(&mut self.as_mut_slice()[row * cols + col])
+        } else {
+            None
+        }
+    }
+
+    /// Borrows the row `row` as a [`VecSlice`].
+    ///
+    /// The elements of the row can be modified freely, but its length must be the same
+    /// when the grid is used again, otherwise the grid panics.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row >= rows`.
+    pub fn row(&mut self, row: usize) -> VecSlice<'_, T, S> {
+        self.check();
+        assert!(row < self.rows, "row index (is {row}) should be < rows (is {})", self.rows);
+        let start = self.start + row * self.cols;
+        VecSlice::lent(start, start + self.cols, self.original)
+    }
+
+    /// Returns an iterator over the rows of the grid.
+    pub fn iter_rows(&self) -> std::slice::ChunksExact<'_, T> {
+        self.as_slice().chunks_exact(self.cols)
+    }
+
+    /// Returns an iterator over the rows of the grid that allows modifying each value.
+    pub fn iter_rows_mut(&mut self) -> std::slice::ChunksExactMut<'_, T> {
+        let cols = self.cols;
+        self.as_mut_slice().chunks_exact_mut(cols)
+    }
+
+    /// Appends a new row with the elements of `row` at the bottom of the grid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` does not have exactly `cols` elements.
+    pub fn push_row<I: IntoIterator<Item = T>>(&mut self, row: I) {
+        self.check();
+        let end = self.start + self.rows * self.cols;
+        let inserted = self.original.splice_range(end, end, row);
+        if inserted != self.cols {
+            self.original.clear_range(end, end + inserted);
+            panic!("row length (is {inserted}) should be equal to cols (is {})", self.cols);
+        }
+        self.rows += 1;
+        self.len = self.original.len();
+    }
+
+    /// Removes the row `row` from the grid, returning its elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row >= rows`.
+    pub fn remove_row(&mut self, row: usize) -> Vec<T> {
+        let removed = self.row(row).into_iter().collect();
+        self.rows -= 1;
+        self.len = self.original.len();
+        removed
+    }
+
+    /// Checks that no borrowed row has changed its length.
+    fn check(&self) {
+        assert!(self.original.len() == self.len, "a row of the grid changed its length");
+    }
+}
+
+impl<T, S: Sliceable<T>> core::ops::Index<(usize, usize)> for VecSlice2D<'_, T, S> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(row < self.rows && col < self.cols, "index ({row}, {col}) out of range for grid of shape ({}, {})", self.rows, self.cols);
+        &self.as_slice()[row * self.cols + col]
+    }
+}
+
+impl<T, S: Sliceable<T>> core::ops::IndexMut<(usize, usize)> for VecSlice2D<'_, T, S> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        assert!(row < self.rows && col < self.cols, "index ({row}, {col}) out of range for grid of shape ({}, {})", self.rows, self.cols);
+        let cols = self.cols;
+        &mut self.as_mut_slice()[row * cols + col]
+    }
+}
+
+impl<T: core::fmt::Debug, S: Sliceable<T>> core::fmt::Debug for VecSlice2D<'_, T, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter_rows()).finish()
+    }
+}
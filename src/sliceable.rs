@@ -0,0 +1,388 @@
+use std::collections::VecDeque;
+
+use crate::CapacityError;
+
+/// Collection that can be used as the underlying buffer of a [`VecSlice`](crate::VecSlice).
+///
+/// Only [`Sliceable::len`], [`Sliceable::insert`], [`Sliceable::remove`] and the slice accessors are required,
+/// the bulk operations have default implementations built on top of them that can be overridden with faster versions.
+///
+/// Besides the collections of this crate and the standard library, it is implemented for `&mut S`, `Box<S>`
+/// and the [`RefCell`](std::cell::RefCell), [`Mutex`](std::sync::Mutex) and [`RwLock`](std::sync::RwLock) write guards
+/// of any `S: Sliceable`, forwarding to it.
+///
+/// # Examples
+///
+/// ```
+/// use vecslice::{Slice, Sliceable};
+///
+/// struct Stack(Vec<i32>);
+///
+/// impl Sliceable<i32> for Stack {
+///     fn len(&self) -> usize {
+///         self.0.len()
+///     }
+///
+///     fn insert(&mut self, index: usize, element: i32) {
+///         self.0.insert(index, element);
+///     }
+///
+///     fn remove(&mut self, index: usize) -> i32 {
+///         self.0.remove(index)
+///     }
+///
+///     fn as_slice(&self) -> &[i32] {
+///         &self.0
+///     }
+///
+///     fn as_mut_slice(&mut self) -> &mut [i32] {
+///         &mut self.0
+///     }
+/// }
+///
+/// let mut stack = Stack(vec![1, 2, 3]);
+/// let mut slice = stack.vecslice(1..);
+/// slice.push_front(4);
+/// assert_eq!(slice, [4, 2, 3]);
+/// assert_eq!(stack.0, [1, 4, 2, 3]);
+/// ```
+pub trait Sliceable<T> {
+    /// Returns the number of elements in the collection.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the collection contains no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Inserts an element at position `index`, shifting all elements after it to the right.
+    fn insert(&mut self, index: usize, element: T);
+
+    /// Removes and returns the element at position `index`, shifting all elements after it to the left.
+    fn remove(&mut self, index: usize) -> T;
+
+    /// Returns a slice containing all the elements of the collection.
+    fn as_slice(&self) -> &[T];
+
+    /// Returns a mutable slice containing all the elements of the collection.
+    fn as_mut_slice(&mut self) -> &mut [T];
+
+    /// Returns a mutable slice containing the elements in `start..end`.
+    ///
+    /// Every mutable access a [`VecSlice`](crate::VecSlice) makes to its elements goes through this method,
+    /// so it can be overridden to know which elements may have been modified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    fn as_mut_range(&mut self, start: usize, end: usize) -> &mut [T] {
+        &mut self.as_mut_slice()[start..end]
+    }
+
+    /// Rearranges the elements so they are stored contiguously, called before the collection is sliced.
+    ///
+    /// Collections that can always return their elements as a slice don't need to do anything, which is the default.
+    fn make_contiguous(&mut self) {}
+
+    /// Returns the maximum number of elements the collection can hold, or [`None`] if it can grow without limit.
+    fn capacity(&self) -> Option<usize> {
+        None
+    }
+
+    /// Reserves space for at least `additional` more elements, if the collection can grow.
+    ///
+    /// Called before inserting several elements whose number is known in advance, the default implementation does nothing.
+    fn reserve(&mut self, additional: usize) {
+        let _ = additional;
+    }
+
+    /// Removes the elements in `start..end`.
+    ///
+    /// The default implementation removes the elements one by one, starting from the last one
+    /// so the removed elements are never shifted.
+    fn clear_range(&mut self, start: usize, end: usize) {
+        for index in (start..end).rev() {
+            self.remove(index);
+        }
+    }
+
+    /// Replaces the elements in `start..end` with `replace_with`, returning the number of inserted elements.
+    ///
+    /// The default implementation inserts the elements one by one after clearing the range.
+    fn splice_range<I: IntoIterator<Item = T>>(&mut self, start: usize, end: usize, replace_with: I) -> usize {
+        self.clear_range(start, end);
+        let mut index = start;
+        for element in replace_with {
+            self.insert(index, element);
+            index += 1;
+        }
+        index - start
+    }
+}
+
+/// Implements [`Sliceable`] and [`TrySliceable`] for `Vec`, which is generic over its allocator
+/// only when the `allocator_api` feature is enabled.
+macro_rules! vec_sliceable {
+    (impl<$($param:ident $(: $bound:path)?),*> for $vec:ty) => {
+        impl<$($param $(: $bound)?),*> Sliceable<T> for $vec {
+            fn len(&self) -> usize {
+                Vec::len(self)
+            }
+
+            fn insert(&mut self, index: usize, element: T) {
+                Vec::insert(self, index, element);
+            }
+
+            fn remove(&mut self, index: usize) -> T {
+                Vec::remove(self, index)
+            }
+
+            fn as_slice(&self) -> &[T] {
+                self
+            }
+
+            fn as_mut_slice(&mut self) -> &mut [T] {
+                self
+            }
+
+            fn reserve(&mut self, additional: usize) {
+                Vec::reserve(self, additional);
+            }
+
+            fn clear_range(&mut self, start: usize, end: usize) {
+                self.drain(start..end);
+            }
+
+            fn splice_range<I: IntoIterator<Item = T>>(&mut self, start: usize, end: usize, replace_with: I) -> usize {
+                let len = Vec::len(self) - (end - start);
+                self.splice(start..end, replace_with);
+                Vec::len(self) - len
+            }
+        }
+
+        impl<$($param $(: $bound)?),*> TrySliceable<T> for $vec {
+            fn try_insert(&mut self, index: usize, element: T) -> Result<(), CapacityError<T>> {
+                Vec::insert(self, index, element);
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "allocator_api"))]
+vec_sliceable!(impl<T> for Vec<T>);
+#[cfg(feature = "allocator_api")]
+vec_sliceable!(impl<T, A: std::alloc::Allocator> for Vec<T, A>);
+
+/// The elements are kept contiguous after every operation, which can move all of them
+/// when an insertion or removal wraps around the ring buffer.
+///
+/// # Panics
+///
+/// [`Sliceable::as_slice`] panics if the deque is not contiguous, which can only happen if it is modified directly
+/// and then used without creating a new [`VecSlice`](crate::VecSlice), for example through a [`VecSliceRef`](crate::VecSliceRef).
+/// Call [`VecDeque::make_contiguous`] beforehand in that case.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use vecslice::Slice;
+///
+/// let mut deque = VecDeque::from([1, 2, 3]);
+/// deque.push_front(0);
+///
+/// let mut slice = deque.vecslice(1..3);
+/// slice.push_back(4);
+/// slice.remove(0);
+/// assert_eq!(slice, [2, 4]);
+/// assert_eq!(deque, [0, 2, 4, 3]);
+/// ```
+impl<T> Sliceable<T> for VecDeque<T> {
+    fn len(&self) -> usize {
+        VecDeque::len(self)
+    }
+
+    fn insert(&mut self, index: usize, element: T) {
+        VecDeque::insert(self, index, element);
+        VecDeque::make_contiguous(self);
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        let len = VecDeque::len(self);
+        let element = VecDeque::remove(self, index).unwrap_or_else(|| panic!("removal index (is {index}) should be < len (is {len})"));
+        VecDeque::make_contiguous(self);
+        element
+    }
+
+    fn as_slice(&self) -> &[T] {
+        let (front, back) = self.as_slices();
+        assert!(back.is_empty(), "VecDeque is not contiguous, call `make_contiguous` first");
+        front
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        VecDeque::make_contiguous(self)
+    }
+
+    fn make_contiguous(&mut self) {
+        VecDeque::make_contiguous(self);
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        VecDeque::reserve(self, additional);
+    }
+
+    fn clear_range(&mut self, start: usize, end: usize) {
+        self.drain(start..end);
+        VecDeque::make_contiguous(self);
+    }
+
+    fn splice_range<I: IntoIterator<Item = T>>(&mut self, start: usize, end: usize, replace_with: I) -> usize {
+        self.drain(start..end);
+        let mut tail = self.split_off(start);
+        self.extend(replace_with);
+        let inserted = VecDeque::len(self) - start;
+        self.append(&mut tail);
+        VecDeque::make_contiguous(self);
+        inserted
+    }
+}
+
+/// [`Sliceable`] collection with a fixed capacity, that can report a failed insertion instead of panicking.
+///
+/// Used by [`VecSlice::try_push_back`](crate::VecSlice::try_push_back) and [`VecSlice::try_push_front`](crate::VecSlice::try_push_front).
+///
+/// # Examples
+///
+/// ```
+/// use vecslice::{CapacityError, Slice, Sliceable, TrySliceable};
+///
+/// struct Bounded(Vec<i32>);
+///
+/// impl Sliceable<i32> for Bounded {
+///     fn len(&self) -> usize {
+///         self.0.len()
+///     }
+///
+///     fn insert(&mut self, index: usize, element: i32) {
+///         self.try_insert(index, element).unwrap();
+///     }
+///
+///     fn remove(&mut self, index: usize) -> i32 {
+///         self.0.remove(index)
+///     }
+///
+///     fn as_slice(&self) -> &[i32] {
+///         &self.0
+///     }
+///
+///     fn as_mut_slice(&mut self) -> &mut [i32] {
+///         &mut self.0
+///     }
+///
+///     fn capacity(&self) -> Option<usize> {
+///         Some(3)
+///     }
+/// }
+///
+/// impl TrySliceable<i32> for Bounded {
+///     fn try_insert(&mut self, index: usize, element: i32) -> Result<(), CapacityError<i32>> {
+///         if self.0.len() == 3 {
+///             return Err(CapacityError::new(element));
+///         }
+///         self.0.insert(index, element);
+///         Ok(())
+///     }
+/// }
+///
+/// let mut bounded = Bounded(vec![1, 2]);
+/// let mut slice = bounded.vecslice(..1);
+/// assert_eq!(slice.try_push_back(3), Ok(()));
+/// assert_eq!(slice.try_push_front(4), Err(CapacityError::new(4)));
+/// assert_eq!(bounded.0, [1, 3, 2]);
+/// ```
+pub trait TrySliceable<T>: Sliceable<T> {
+    /// Inserts an element at position `index`, shifting all elements after it to the right,
+    /// or returns it back in an error if the collection is full.
+    fn try_insert(&mut self, index: usize, element: T) -> Result<(), CapacityError<T>>;
+}
+
+impl<T> TrySliceable<T> for VecDeque<T> {
+    fn try_insert(&mut self, index: usize, element: T) -> Result<(), CapacityError<T>> {
+        Sliceable::insert(self, index, element);
+        Ok(())
+    }
+}
+
+/// Implements [`Sliceable`] and [`TrySliceable`] for a pointer type by forwarding every method to its target,
+/// so the overridden bulk operations of the target are kept.
+macro_rules! forward_sliceable {
+    ($(impl<$($lt:lifetime,)? S> for $ty:ty;)*) => {$(
+        impl<$($lt,)? T, S: Sliceable<T>> Sliceable<T> for $ty {
+            fn len(&self) -> usize {
+                (**self).len()
+            }
+
+            fn is_empty(&self) -> bool {
+                (**self).is_empty()
+            }
+
+            fn insert(&mut self, index: usize, element: T) {
+                (**self).insert(index, element);
+            }
+
+            fn remove(&mut self, index: usize) -> T {
+                (**self).remove(index)
+            }
+
+            fn as_slice(&self) -> &[T] {
+                (**self).as_slice()
+            }
+
+            fn as_mut_slice(&mut self) -> &mut [T] {
+                (**self).as_mut_slice()
+            }
+
+            fn as_mut_range(&mut self, start: usize, end: usize) -> &mut [T] {
+                (**self).as_mut_range(start, end)
+            }
+
+            fn make_contiguous(&mut self) {
+                (**self).make_contiguous();
+            }
+
+            fn capacity(&self) -> Option<usize> {
+                (**self).capacity()
+            }
+
+            fn reserve(&mut self, additional: usize) {
+                (**self).reserve(additional);
+            }
+
+            fn clear_range(&mut self, start: usize, end: usize) {
+                (**self).clear_range(start, end);
+            }
+
+            fn splice_range<I: IntoIterator<Item = T>>(&mut self, start: usize, end: usize, replace_with: I) -> usize {
+                (**self).splice_range(start, end, replace_with)
+            }
+        }
+
+        impl<$($lt,)? T, S: TrySliceable<T>> TrySliceable<T> for $ty {
+            fn try_insert(&mut self, index: usize, element: T) -> Result<(), CapacityError<T>> {
+                (**self).try_insert(index, element)
+            }
+        }
+    )*};
+}
+
+// A blanket implementation for every `DerefMut` type would conflict with the ones for collections like `Vec`,
+// which dereference to a slice, so only the standard pointers and guards are covered.
+forward_sliceable! {
+    impl<'a, S> for &'a mut S;
+    impl<S> for Box<S>;
+    impl<'a, S> for std::cell::RefMut<'a, S>;
+    impl<'a, S> for std::sync::MutexGuard<'a, S>;
+    impl<'a, S> for std::sync::RwLockWriteGuard<'a, S>;
+}
@@ -0,0 +1,200 @@
+use core::marker::PhantomData;
+
+use crate::{Sliceable, VecSlice};
+
+/// Returns where `pos` ends up after the buffer changes from `old_len` to `new_len` elements,
+/// when all the insertions and removals happened before it.
+fn moved(pos: usize, old_len: usize, new_len: usize) -> usize {
+    (pos + new_len).checked_sub(old_len).expect("more elements were removed than the borrowed part had")
+}
+
+/// Disjoint windows over the same buffer that are lent one at a time, shared by [`SplitAt`] and [`Splits`].
+///
+/// The change in the length of the buffer is attributed to the window that was lent last,
+/// so it is synced before lending the next one.
+struct Lender<'a, T, S: Sliceable<T>, B> {
+    original: Option<&'a mut S>,
+    start: usize,
+    end: usize,
+    /// Limits of the slice that was split, restored when joined.
+    limits: (usize, usize),
+    /// Absolute bounds of every window, sorted and disjoint.
+    bounds: B,
+    /// Length of `original` when `last` was lent.
+    len: usize,
+    last: Option<usize>,
+    marker: PhantomData<T>,
+}
+
+impl<'a, T, S: Sliceable<T>, B: AsMut<[(usize, usize)]>> Lender<'a, T, S, B> {
+    fn new(original: &'a mut S, start: usize, end: usize, limits: (usize, usize), bounds: B) -> Self {
+        let len = original.len();
+        Lender { original: Some(original), start, end, limits, bounds, len, last: None, marker: PhantomData }
+    }
+
+    /// Moves the boundaries to account for the elements added or removed by the last lent window.
+    fn sync(&mut self) {
+        let Some(original) = self.original.as_deref() else {
+            return;
+        };
+        let (old_len, len) = (self.len, original.len());
+        if let Some(last) = self.last {
+            let bounds = self.bounds.as_mut();
+            bounds[last].1 = moved(bounds[last].1, old_len, len);
+            for (start, end) in &mut bounds[last + 1..] {
+                (*start, *end) = (moved(*start, old_len, len), moved(*end, old_len, len));
+            }
+            self.end = moved(self.end, old_len, len);
+        }
+        self.len = len;
+    }
+
+    fn lend(&mut self, index: usize) -> VecSlice<'_, T, S> {
+        self.sync();
+        self.last = Some(index);
+        let (start, end) = self.bounds.as_mut()[index];
+        let original = self.original.as_deref_mut().expect("split is not joined");
+        VecSlice::lent(start, end, original)
+    }
+
+    fn join(&mut self) -> VecSlice<'a, T, S> {
+        self.sync();
+        let original = self.original.take().expect("split is not joined");
+        VecSlice::with_limits(self.start, self.end, original, self.limits)
+    }
+}
+
+/// Two adjacent windows over the same buffer.
+///
+/// This `struct` is created by [`VecSlice::split_at`] and [`VecSlice::split_at_mut`].
+///
+/// Only one half can be borrowed at a time, but both of them can grow and shrink independently:
+/// when a half is borrowed again, the change in the length of the buffer is attributed to the half that was borrowed last,
+/// and the boundaries are moved accordingly.
+///
+/// # Examples
+///
+/// ```
+/// use vecslice::Slice;
+///
+/// let mut vec = vec![0, 1, 2, 3, 4, 5];
+/// let slice = vec.vecslice(1..5);
+///
+/// let mut split = slice.split_at(2);
+/// split.left().push_back(6);
+/// split.right().push_front(7);
+/// split.left().pop_front();
+/// assert_eq!(split.left(), [2, 6]);
+/// assert_eq!(split.right(), [7, 3, 4]);
+///
+/// let slice = split.join();
+/// assert_eq!(slice, [2, 6, 7, 3, 4]);
+/// assert_eq!(vec, [0, 2, 6, 7, 3, 4, 5]);
+/// ```
+pub struct SplitAt<'a, T, S: Sliceable<T> = Vec<T>> {
+    halves: Lender<'a, T, S, [(usize, usize); 2]>,
+    /// End of the slice that was split by [`VecSlice::split_at_mut`], updated when the split is dropped.
+    parent_end: Option<&'a mut usize>,
+}
+
+impl<'a, T, S: Sliceable<T>> SplitAt<'a, T, S> {
+    pub(crate) fn new(original: &'a mut S, start: usize, mid: usize, end: usize, limits: (usize, usize), parent_end: Option<&'a mut usize>) -> Self {
+        assert!(start <= mid && mid <= end);
+        SplitAt { halves: Lender::new(original, start, end, limits, [(start, mid), (mid, end)]), parent_end }
+    }
+
+    /// Moves the boundaries to account for the elements added or removed by the last borrowed half.
+    fn sync(&mut self) {
+        self.halves.sync();
+        if let Some(parent_end) = self.parent_end.as_deref_mut() {
+            *parent_end = self.halves.end;
+        }
+    }
+
+    /// Borrows the left half of the split.
+    pub fn left(&mut self) -> VecSlice<'_, T, S> {
+        self.halves.lend(0)
+    }
+
+    /// Borrows the right half of the split.
+    pub fn right(&mut self) -> VecSlice<'_, T, S> {
+        self.halves.lend(1)
+    }
+
+    /// Joins both halves back into a single slice.
+    ///
+    /// If the split was created by [`VecSlice::split_at_mut`], the original slice is updated as well,
+    /// but later changes made through the joined slice are not reflected on it.
+    pub fn join(mut self) -> VecSlice<'a, T, S> {
+        self.sync();
+        self.halves.join()
+    }
+}
+
+impl<T, S: Sliceable<T>> Drop for SplitAt<'_, T, S> {
+    fn drop(&mut self) {
+        self.sync();
+    }
+}
+
+/// Several disjoint windows over the same buffer.
+///
+/// This `struct` is created by [`VecSlice::into_split`].
+///
+/// Like [`SplitAt`], only one part can be borrowed at a time, but all of them can grow and shrink independently:
+/// when a part is borrowed again, the change in the length of the buffer is attributed to the part that was borrowed last,
+/// and the following parts are moved accordingly.
+///
+/// # Examples
+///
+/// ```
+/// use vecslice::Slice;
+///
+/// let mut vec = vec![0, 1, 2, 3, 4, 5, 6];
+/// let slice = vec.vecslice(1..6);
+///
+/// let mut parts = slice.into_split([0..2, 3..5]);
+/// parts.part(0).push_back(7);
+/// parts.part(1).pop_front();
+/// parts.part(0).pop_front();
+/// assert_eq!(parts.part(0), [2, 7]);
+/// assert_eq!(parts.part(1), [5]);
+///
+/// let slice = parts.join();
+/// assert_eq!(slice, [2, 7, 3, 5]);
+/// assert_eq!(vec, [0, 2, 7, 3, 5, 6]);
+/// ```
+pub struct Splits<'a, T, S: Sliceable<T> = Vec<T>> {
+    parts: Lender<'a, T, S, Vec<(usize, usize)>>,
+}
+
+impl<'a, T, S: Sliceable<T>> Splits<'a, T, S> {
+    pub(crate) fn new(original: &'a mut S, start: usize, end: usize, limits: (usize, usize), parts: Vec<(usize, usize)>) -> Self {
+        Splits { parts: Lender::new(original, start, end, limits, parts) }
+    }
+
+    /// Returns the number of parts.
+    pub fn len(&self) -> usize {
+        self.parts.bounds.len()
+    }
+
+    /// Returns `true` if there are no parts.
+    pub fn is_empty(&self) -> bool {
+        self.parts.bounds.is_empty()
+    }
+
+    /// Borrows the part at position `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn part(&mut self, index: usize) -> VecSlice<'_, T, S> {
+        assert!(index < self.len(), "part index (is {index}) should be < len (is {})", self.len());
+        self.parts.lend(index)
+    }
+
+    /// Joins all the parts back into the slice they were split from, including the elements between them.
+    pub fn join(mut self) -> VecSlice<'a, T, S> {
+        self.parts.join()
+    }
+}
@@ -0,0 +1,254 @@
+use core::ops::RangeBounds;
+
+/// Growable mutable reference on a [`String`].
+///
+/// Works like a [`VecSlice`](crate::VecSlice) over the bytes of the string,
+/// but every position is checked to be on a `char` boundary, so the string is always valid UTF-8.
+/// All positions are byte indices relative to the slice.
+///
+/// # Examples
+///
+/// ```
+/// use vecslice::StrSlice;
+///
+/// let mut string = String::from("hello world");
+/// let mut slice = StrSlice::new(6.., &mut string);
+/// assert_eq!(slice, "world");
+///
+/// slice.insert_str(0, "big ");
+/// slice.push('!');
+/// assert_eq!(slice, "big world!");
+/// assert_eq!(string, "hello big world!");
+/// ```
+pub struct StrSlice<'a> {
+    start: usize,
+    end: usize,
+    original: &'a mut String,
+}
+
+impl<'a> StrSlice<'a> {
+    /// Creates a new [`StrSlice`] of `original` on the specified byte range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or if any of its ends is not on a `char` boundary.
+    pub fn new(range: impl RangeBounds<usize>, original: &'a mut String) -> StrSlice<'a> {
+        let (start, end) = crate::VecSlice::<u8>::translate_range(range, 0, original.len());
+        assert!(start <= end && end <= original.len(), "byte range {start}..{end} out of range for string of length {}", original.len());
+        assert!(original.is_char_boundary(start), "byte index {start} is not a char boundary");
+        assert!(original.is_char_boundary(end), "byte index {end} is not a char boundary");
+        StrSlice { start, end, original }
+    }
+
+    /// Consumes the slice, returning the mutable reference to the underlying string.
+    pub fn into_original(self) -> &'a mut String {
+        self.original
+    }
+
+    /// Returns the length of the slice in bytes.
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns `true` if the slice has a length of zero bytes.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Extracts a string slice containing the slice.
+    pub fn as_str(&self) -> &str {
+        &self.original[self.start..self.end]
+    }
+
+    /// Extracts a mutable string slice containing the slice.
+    pub fn as_mut_str(&mut self) -> &mut str {
+        &mut self.original[self.start..self.end]
+    }
+
+    /// Appends the given `char` to the end of the slice.
+    pub fn push(&mut self, ch: char) {
+        self.original.insert(self.end, ch);
+        self.end += ch.len_utf8();
+    }
+
+    /// Appends the given string to the end of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::StrSlice;
+    ///
+    /// let mut string = String::from("foo baz");
+    /// let mut slice = StrSlice::new(..3, &mut string);
+    /// slice.push_str("bar");
+    /// assert_eq!(string, "foobar baz");
+    /// ```
+    pub fn push_str(&mut self, string: &str) {
+        self.original.insert_str(self.end, string);
+        self.end += string.len();
+    }
+
+    /// Inserts a `char` at byte position `index` within the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is larger than the length of the slice, or if it does not lie on a `char` boundary.
+    pub fn insert(&mut self, index: usize, ch: char) {
+        let index = self.checked_index(index);
+        self.original.insert(index, ch);
+        self.end += ch.len_utf8();
+    }
+
+    /// Inserts a string at byte position `index` within the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is larger than the length of the slice, or if it does not lie on a `char` boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::StrSlice;
+    ///
+    /// let mut string = String::from("<ñu>");
+    /// let mut slice = StrSlice::new(1..4, &mut string);
+    /// slice.insert_str(2, "--");
+    /// assert_eq!(slice, "ñ--u");
+    /// assert_eq!(string, "<ñ--u>");
+    /// ```
+    pub fn insert_str(&mut self, index: usize, string: &str) {
+        let index = self.checked_index(index);
+        self.original.insert_str(index, string);
+        self.end += string.len();
+    }
+
+    /// Removes the last `char` from the slice and returns it, or [`None`] if it is empty.
+    pub fn pop(&mut self) -> Option<char> {
+        let ch = self.as_str().chars().next_back()?;
+        self.end -= ch.len_utf8();
+        self.original.remove(self.end);
+        Some(ch)
+    }
+
+    /// Removes the first `char` from the slice and returns it, or [`None`] if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::StrSlice;
+    ///
+    /// let mut string = String::from("añb");
+    /// let mut slice = StrSlice::new(1.., &mut string);
+    /// assert_eq!(slice.pop_front_char(), Some('ñ'));
+    /// assert_eq!(slice.pop_front_char(), Some('b'));
+    /// assert_eq!(slice.pop_front_char(), None);
+    /// assert_eq!(string, "a");
+    /// ```
+    pub fn pop_front_char(&mut self) -> Option<char> {
+        let ch = self.as_str().chars().next()?;
+        self.end -= ch.len_utf8();
+        Some(self.original.remove(self.start))
+    }
+
+    /// Removes the specified byte range from the slice in bulk, returning all removed characters as an iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of the bounds of the slice, or if any of its ends does not lie on a `char` boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::StrSlice;
+    ///
+    /// let mut string = String::from("α is alpha, β is beta");
+    /// let mut slice = StrSlice::new(13.., &mut string);
+    /// let removed: String = slice.drain(..6).collect();
+    /// assert_eq!(removed, "β is ");
+    /// assert_eq!(slice, "beta");
+    /// assert_eq!(string, "α is alpha, beta");
+    /// ```
+    pub fn drain(&mut self, range: impl RangeBounds<usize>) -> std::string::Drain<'_> {
+        let (start, end) = crate::VecSlice::<u8>::translate_range(range, 0, self.len());
+        assert!(start <= end && end <= self.len(), "byte range {start}..{end} out of range for slice of length {}", self.len());
+        let (start, end) = (self.checked_index(start), self.checked_index(end));
+        self.end -= end - start;
+        self.original.drain(start..end)
+    }
+
+    /// Shortens the slice to the specified length in bytes.
+    ///
+    /// If `new_len` is greater than or equal to the current length, this has no effect.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` does not lie on a `char` boundary.
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len() {
+            let index = self.checked_index(new_len);
+            self.original.drain(index..self.end);
+            self.end = index;
+        }
+    }
+
+    /// Removes all the contents of the slice from the string.
+    pub fn clear(&mut self) {
+        self.original.drain(self.start..self.end);
+        self.end = self.start;
+    }
+
+    /// Translates a position relative to the slice into one in the string, checking it is valid.
+    fn checked_index(&self, index: usize) -> usize {
+        assert!(index <= self.len(), "byte index {index} out of range for slice of length {}", self.len());
+        assert!(self.as_str().is_char_boundary(index), "byte index {index} is not a char boundary");
+        self.start + index
+    }
+}
+
+impl<'a> From<&'a mut String> for StrSlice<'a> {
+    fn from(original: &'a mut String) -> Self {
+        Self::new(.., original)
+    }
+}
+
+impl core::fmt::Debug for StrSlice<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl core::fmt::Display for StrSlice<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl PartialEq for StrSlice<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl PartialEq<str> for StrSlice<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for StrSlice<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl AsRef<str> for StrSlice<'_> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsMut<str> for StrSlice<'_> {
+    fn as_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
@@ -0,0 +1,81 @@
+//! [`proptest`] strategies to property-test [`Sliceable`] backends, behind the `proptest` feature.
+//!
+//! [`vec_and_range`] generates a buffer and a window on it, [`ops`] generates the operations applied to the window,
+//! and [`check_model`] applies them to a [`VecSlice`] over any backend and to a [`Vec`] model, failing on the first difference.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::collections::VecDeque;
+//!
+//! use proptest::prelude::*;
+//! use vecslice::strategies::{check_model, ops, vec_and_range};
+//!
+//! proptest!(|((vec, range) in vec_and_range(any::<u8>(), 0..32), ops in ops(any::<u8>(), 0..32))| {
+//!     check_model(&mut VecDeque::from(vec), range, ops)?;
+//! });
+//! ```
+
+use core::fmt::Debug;
+use core::ops::Range;
+
+use proptest::collection::{vec, SizeRange};
+use proptest::prelude::*;
+use proptest::sample::Index;
+use proptest::test_runner::TestCaseError;
+
+use crate::fuzz::{apply, apply_model, Op};
+use crate::{Sliceable, VecSlice};
+
+/// Generates a [`Vec`] with a length in `size` and a valid range on it, which may be empty.
+pub fn vec_and_range<T: Debug, E: Strategy<Value = T>>(element: E, size: impl Into<SizeRange>) -> impl Strategy<Value = (Vec<T>, Range<usize>)> {
+    (vec(element, size), any::<Index>(), any::<Index>()).prop_map(|(vec, a, b)| {
+        let (a, b) = (a.index(vec.len() + 1), b.index(vec.len() + 1));
+        (vec, a.min(b)..a.max(b))
+    })
+}
+
+/// Generates a single [`Op`], using `element` for the inserted elements.
+pub fn op<T: Clone + Debug, E: Strategy<Value = T> + Clone>(element: E) -> impl Strategy<Value = Op<T>> {
+    prop_oneof![
+        element.clone().prop_map(Op::PushBack),
+        element.clone().prop_map(Op::PushFront),
+        Just(Op::PopBack),
+        Just(Op::PopFront),
+        (any::<usize>(), element).prop_map(|(index, element)| Op::Insert { index, element }),
+        any::<usize>().prop_map(|index| Op::Remove { index }),
+        (any::<usize>(), any::<usize>()).prop_map(|(start, end)| Op::Drain { start, end }),
+    ]
+}
+
+/// Generates a sequence of [`Op`]s with a length in `size`, using `element` for the inserted elements.
+pub fn ops<T: Clone + Debug, E: Strategy<Value = T> + Clone>(element: E, size: impl Into<SizeRange>) -> impl Strategy<Value = Vec<Op<T>>> {
+    vec(op(element), size)
+}
+
+/// Applies `ops` to a [`VecSlice`] of `buffer` on `range` and to a [`Vec`] model of it,
+/// failing if they return different elements or end up with different contents.
+///
+/// # Panics
+///
+/// Panics if `range` is out of bounds of `buffer`.
+pub fn check_model<T, S>(buffer: &mut S, range: Range<usize>, ops: Vec<Op<T>>) -> Result<(), TestCaseError>
+where
+    T: Clone + PartialEq + Debug,
+    S: Sliceable<T>,
+{
+    let before = buffer.as_slice()[..range.start].to_vec();
+    let after = buffer.as_slice()[range.end..].to_vec();
+    let mut model = buffer.as_slice()[range.clone()].to_vec();
+
+    let mut slice = VecSlice::new(range, buffer);
+    for op in ops {
+        let removed = apply(&mut slice, op.clone());
+        prop_assert_eq!(removed, apply_model(&mut model, op.clone()), "elements removed by {:?}", op);
+        prop_assert_eq!(slice.as_slice(), model.as_slice(), "slice after {:?}", op);
+    }
+
+    let expected: Vec<T> = before.into_iter().chain(model).chain(after).collect();
+    prop_assert_eq!(buffer.as_slice(), expected.as_slice(), "whole buffer");
+    Ok(())
+}
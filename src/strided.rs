@@ -0,0 +1,132 @@
+/// View over every `step`-th element of a range of a buffer.
+///
+/// This `struct` is created by [`Slice::vecslice_strided`](crate::Slice::vecslice_strided) and [`VecSlice::strided`](crate::VecSlice::strided).
+///
+/// The view can't grow or shrink, as that would move the elements in between.
+/// All positions are relative to the view, so index `i` is element `i * step` of the range.
+///
+/// # Examples
+///
+/// ```
+/// use vecslice::Slice;
+///
+/// // Interleaved stereo samples
+/// let mut samples = vec![1, -1, 2, -2, 3, -3];
+///
+/// let mut right = samples.vecslice_strided(1.., 2);
+/// assert_eq!(right.len(), 3);
+/// assert_eq!(right, [-1, -2, -3]);
+///
+/// right.fill(0);
+/// assert_eq!(samples, [1, 0, 2, 0, 3, 0]);
+/// ```
+pub struct StridedSlice<'a, T> {
+    slice: &'a mut [T],
+    step: usize,
+}
+
+impl<'a, T> StridedSlice<'a, T> {
+    pub(crate) fn new(slice: &'a mut [T], step: usize) -> Self {
+        assert!(step != 0, "step must be non-zero");
+        StridedSlice { slice, step }
+    }
+
+    /// Returns the number of elements in the view.
+    pub fn len(&self) -> usize {
+        self.slice.len().div_ceil(self.step)
+    }
+
+    /// Returns `true` if the view has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.slice.is_empty()
+    }
+
+    /// Returns the distance between two consecutive elements of the view in the buffer.
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    /// Returns a reference to the element at position `index` within the view, or [`None`] if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.slice.get(index.checked_mul(self.step)?)
+    }
+
+    /// Returns a mutable reference to the element at position `index` within the view, or [`None`] if it is out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.slice.get_mut(index.checked_mul(self.step)?)
+    }
+
+    /// Returns an iterator over the elements of the view.
+    pub fn iter(&self) -> core::iter::StepBy<std::slice::Iter<'_, T>> {
+        self.slice.iter().step_by(self.step)
+    }
+
+    /// Returns an iterator that allows modifying each element of the view.
+    pub fn iter_mut(&mut self) -> core::iter::StepBy<std::slice::IterMut<'_, T>> {
+        self.slice.iter_mut().step_by(self.step)
+    }
+
+    /// Fills the view with clones of `value`, leaving the elements in between untouched.
+    pub fn fill(&mut self, value: T) where T: Clone {
+        for elem in self.iter_mut() {
+            elem.clone_from(&value);
+        }
+    }
+
+    /// Copies the elements of the view into a new `Vec`.
+    pub fn to_vec(&self) -> Vec<T> where T: Clone {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T> core::ops::Index<usize> for StridedSlice<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        let len = self.len();
+        self.get(index).unwrap_or_else(|| panic!("index {index} out of range for view of length {len}"))
+    }
+}
+
+impl<T> core::ops::IndexMut<usize> for StridedSlice<'_, T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len();
+        self.get_mut(index).unwrap_or_else(|| panic!("index {index} out of range for view of length {len}"))
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for StridedSlice<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq<[T]> for StridedSlice<'_, T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.len() == other.len() && self.iter().eq(other)
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for StridedSlice<'_, T> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a StridedSlice<'_, T> {
+    type Item = &'a T;
+    type IntoIter = core::iter::StepBy<std::slice::Iter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut StridedSlice<'_, T> {
+    type Item = &'a mut T;
+    type IntoIter = core::iter::StepBy<std::slice::IterMut<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
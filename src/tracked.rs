@@ -0,0 +1,196 @@
+use core::marker::PhantomData;
+
+use crate::{CapacityError, Sliceable, TrySliceable, VecSlice, VecSliceError};
+
+/// Buffer wrapper with a generation counter that is increased on every structural change.
+///
+/// Inserting or removing elements increases the generation, while modifying them in place doesn't.
+/// A [`VecSlice`] over a [`Tracked`] buffer can issue [`TrackedIndex`] values with [`VecSlice::track`],
+/// which are rejected with [`VecSliceError::StaleIndex`] once the buffer has changed underneath them,
+/// instead of silently pointing to a different element.
+///
+/// # Examples
+///
+/// ```
+/// use vecslice::{Slice, Tracked, VecSliceError};
+///
+/// let mut buffer = Tracked::new(vec!['a', 'b', 'c']);
+/// let mut slice = buffer.vecslice(1..);
+///
+/// let b = slice.track(0).unwrap();
+/// *slice.get_tracked_mut(b).unwrap() = 'B';
+/// assert_eq!(slice.get_tracked(b), Ok(&'B'));
+///
+/// slice.push_front('x');
+/// assert_eq!(slice.get_tracked(b), Err(VecSliceError::StaleIndex));
+/// assert_eq!(buffer.into_inner(), ['a', 'x', 'B', 'c']);
+/// ```
+pub struct Tracked<T, S: Sliceable<T> = Vec<T>> {
+    inner: S,
+    generation: u64,
+    marker: PhantomData<T>,
+}
+
+impl<T, S: Sliceable<T>> Tracked<T, S> {
+    /// Wraps `inner`, starting at generation 0.
+    pub fn new(inner: S) -> Self {
+        Tracked { inner, generation: 0, marker: PhantomData }
+    }
+
+    /// Returns a reference to the wrapped buffer.
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Consumes the wrapper, returning the wrapped buffer.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    /// Returns the current generation, which is increased on every insertion or removal.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    fn bump(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+    }
+}
+
+impl<T, S: Sliceable<T>> Sliceable<T> for Tracked<T, S> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn insert(&mut self, index: usize, element: T) {
+        self.inner.insert(index, element);
+        self.bump();
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        let element = self.inner.remove(index);
+        self.bump();
+        element
+    }
+
+    fn as_slice(&self) -> &[T] {
+        self.inner.as_slice()
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self.inner.as_mut_slice()
+    }
+
+    fn as_mut_range(&mut self, start: usize, end: usize) -> &mut [T] {
+        self.inner.as_mut_range(start, end)
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        self.inner.capacity()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional);
+    }
+
+    fn clear_range(&mut self, start: usize, end: usize) {
+        self.inner.clear_range(start, end);
+        if start < end {
+            self.bump();
+        }
+    }
+
+    fn splice_range<I: IntoIterator<Item = T>>(&mut self, start: usize, end: usize, replace_with: I) -> usize {
+        let inserted = self.inner.splice_range(start, end, replace_with);
+        if start < end || inserted > 0 {
+            self.bump();
+        }
+        inserted
+    }
+}
+
+impl<T, S: TrySliceable<T>> TrySliceable<T> for Tracked<T, S> {
+    fn try_insert(&mut self, index: usize, element: T) -> Result<(), CapacityError<T>> {
+        self.inner.try_insert(index, element)?;
+        self.bump();
+        Ok(())
+    }
+}
+
+impl<T, S: Sliceable<T> + Default> Default for Tracked<T, S> {
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+
+impl<T, S: Sliceable<T>> From<S> for Tracked<T, S> {
+    fn from(inner: S) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl<T: core::fmt::Debug, S: Sliceable<T>> core::fmt::Debug for Tracked<T, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Tracked").field("inner", &self.inner.as_slice()).field("generation", &self.generation).finish()
+    }
+}
+
+/// Index into a [`VecSlice`] over a [`Tracked`] buffer that is only valid while the buffer doesn't change structurally.
+///
+/// This `struct` is created by [`VecSlice::track`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TrackedIndex {
+    /// Start of the slice that issued the index.
+    start: usize,
+    index: usize,
+    generation: u64,
+}
+
+impl TrackedIndex {
+    /// Returns the position within the slice the index was issued for.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the generation of the buffer when the index was issued.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+}
+
+impl<T, S: Sliceable<T>> VecSlice<'_, T, Tracked<T, S>> {
+    /// Returns the current generation of the underlying buffer.
+    pub fn generation(&self) -> u64 {
+        self.original.generation()
+    }
+
+    /// Returns a [`TrackedIndex`] for the element at position `index`, or [`None`] if it is out of bounds.
+    pub fn track(&self, index: usize) -> Option<TrackedIndex> {
+        (index < self.len()).then(|| TrackedIndex { start: self.start, index, generation: self.generation() })
+    }
+
+    /// Checks that `tracked` is still valid for this slice, returning its position.
+    fn validate(&self, tracked: TrackedIndex) -> Result<usize, VecSliceError> {
+        if tracked.generation != self.generation() || tracked.start != self.start {
+            Err(VecSliceError::StaleIndex)
+        } else if tracked.index >= self.len() {
+            Err(VecSliceError::IndexOutOfBounds { index: tracked.index, len: self.len() })
+        } else {
+            Ok(tracked.index)
+        }
+    }
+
+    /// Returns a reference to the element `tracked` points to, or an error if the buffer has changed structurally
+    /// since it was issued, or if it was issued by a different slice.
+    pub fn get_tracked(&self, tracked: TrackedIndex) -> Result<&T, VecSliceError> {
+        let index = self.validate(tracked)?;
+        Ok(&self.as_slice()[index])
+    }
+
+    /// Returns a mutable reference to the element `tracked` points to, or an error if the buffer has changed structurally
+    /// since it was issued, or if it was issued by a different slice.
+    pub fn get_tracked_mut(&mut self, tracked: TrackedIndex) -> Result<&mut T, VecSliceError> {
+        let index = self.validate(tracked)?;
+        Ok(&mut self.as_mut_slice()[index])
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{Sliceable, VecSlice};
+
+/// Restores the contents of a slice when dropped, unless disarmed.
+///
+/// While the guard is alive the slice can't grow past its bounds at the start of the transaction,
+/// so those bounds contain every element it may have changed.
+struct Rollback<'s, 'a, T, S: Sliceable<T>> {
+    slice: &'s mut VecSlice<'a, T, S>,
+    snapshot: Option<Vec<T>>,
+    start: usize,
+    /// Number of elements after the slice at the start of the transaction.
+    tail: usize,
+    limits: (usize, usize),
+}
+
+impl<T, S: Sliceable<T>> Drop for Rollback<'_, '_, T, S> {
+    fn drop(&mut self) {
+        let slice = &mut *self.slice;
+        if let Some(snapshot) = self.snapshot.take() {
+            slice.original.clear_range(self.start, slice.original.len() - self.tail);
+            slice.start = self.start;
+            slice.end = self.start + slice.original.splice_range(self.start, self.start, snapshot);
+        }
+        slice.min_start = self.limits.0;
+        slice.max_tail = self.limits.1;
+    }
+}
+
+impl<'a, T, S: Sliceable<T>> VecSlice<'a, T, S> {
+    /// Runs `f` on the slice, undoing all of its edits if it returns an [`Err`] or panics.
+    ///
+    /// Only the elements of the slice are cloned beforehand, not the whole collection,
+    /// and they are moved back into place on rollback.
+    /// While `f` runs, the slice can't grow past its current bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let mut vec = vec![0, 1, 2, 3];
+    /// let mut slice = vec.vecslice(1..3);
+    ///
+    /// // Keep the slice sorted, or don't touch it at all
+    /// let result = slice.transaction(|txn| {
+    ///     txn.push_back(0);
+    ///     txn.push_front(4);
+    ///     if txn.as_slice().is_sorted() { Ok(()) } else { Err("not sorted") }
+    /// });
+    /// assert_eq!(result, Err("not sorted"));
+    /// assert_eq!(slice, [1, 2]);
+    ///
+    /// let result = slice.transaction(|txn| {
+    ///     txn.push_back(5);
+    ///     Ok::<_, ()>(txn.len())
+    /// });
+    /// assert_eq!(result, Ok(3));
+    /// assert_eq!(vec, [0, 1, 2, 5, 3]);
+    /// ```
+    pub fn transaction<R, E, F: FnOnce(&mut Self) -> Result<R, E>>(&mut self, f: F) -> Result<R, E> where T: Clone {
+        let snapshot = self.to_vec();
+        let (start, tail, limits) = (self.start, self.original.len() - self.end, self.limits());
+        self.min_start = start;
+        self.max_tail = tail;
+        let mut rollback = Rollback { slice: self, snapshot: Some(snapshot), start, tail, limits };
+        let result = f(rollback.slice);
+        if result.is_ok() {
+            rollback.snapshot = None;
+        }
+        result
+    }
+}
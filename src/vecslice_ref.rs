@@ -0,0 +1,217 @@
+use core::marker::PhantomData;
+use core::ops::RangeBounds;
+
+use crate::{Sliceable, VecSlice, VecSliceError};
+
+/// Read-only window over a range of a collection.
+///
+/// Works like a [`VecSlice`], but only holds a shared reference to the collection,
+/// so any number of them can be alive at the same time, and they can be copied freely.
+/// All positions are relative to the window.
+///
+/// # Examples
+///
+/// ```
+/// use vecslice::{Slice, VecSliceRef};
+///
+/// let vec = vec![0, 1, 2, 3, 4, 5];
+/// let head = vec.vecslice_ref(..2);
+/// let tail = VecSliceRef::new(3.., &vec);
+///
+/// assert_eq!(head, [0, 1]);
+/// assert_eq!(tail, [3, 4, 5]);
+/// assert_eq!(tail.get(1), Some(&4));
+/// assert!(head < tail);
+/// assert_eq!(head.iter().chain(&tail).sum::<i32>(), 13);
+/// ```
+pub struct VecSliceRef<'a, T, S: Sliceable<T> = Vec<T>> {
+    start: usize,
+    end: usize,
+    original: &'a S,
+    marker: PhantomData<T>,
+}
+
+impl<'a, T, S: Sliceable<T>> VecSliceRef<'a, T, S> {
+    /// Creates a new [`VecSliceRef`] of `original` on the specified range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn new(range: impl RangeBounds<usize>, original: &'a S) -> VecSliceRef<'a, T, S> {
+        let (start, end) = VecSlice::<T, S>::translate_range(range, 0, original.len());
+        assert!(start <= end && end <= original.len(), "range {start}..{end} out of range for slice of length {}", original.len());
+        VecSliceRef { start, end, original, marker: PhantomData }
+    }
+
+    /// Creates a new [`VecSliceRef`] of `original` on the specified range, or returns an error if the range is out of bounds.
+    pub fn try_new(range: impl RangeBounds<usize>, original: &'a S) -> Result<VecSliceRef<'a, T, S>, VecSliceError> {
+        let (start, end) = VecSlice::<T, S>::try_translate_range(range, original.len())?;
+        Ok(VecSliceRef { start, end, original, marker: PhantomData })
+    }
+
+    /// Returns the reference to the underlying collection.
+    pub fn original(&self) -> &'a S {
+        self.original
+    }
+
+    /// Returns a narrower window on the specified range, relative to this one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vecslice::Slice;
+    ///
+    /// let vec = vec![0, 1, 2, 3, 4, 5];
+    /// let slice = vec.vecslice_ref(1..5);
+    /// assert_eq!(slice.subslice(1..), [2, 3, 4]);
+    /// assert_eq!(slice.subslice(..=1), [1, 2]);
+    /// ```
+    pub fn subslice(&self, range: impl RangeBounds<usize>) -> VecSliceRef<'a, T, S> {
+        let (start, end) = VecSlice::<T, S>::translate_range(range, 0, self.len());
+        assert!(start <= end && end <= self.len(), "range {start}..{end} out of range for slice of length {}", self.len());
+        VecSliceRef { start: self.start + start, end: self.start + end, original: self.original, marker: PhantomData }
+    }
+
+    /// Returns the number of elements in the window.
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns `true` if the window has a length of 0.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Extracts a slice containing the window, borrowed for as long as the collection.
+    pub fn as_slice(&self) -> &'a [T] {
+        &self.original.as_slice()[self.start..self.end]
+    }
+
+    /// Returns an iterator over the window.
+    pub fn iter(&self) -> std::slice::Iter<'a, T> {
+        self.as_slice().iter()
+    }
+
+    /// Returns a reference to the element at position `index`, or [`None`] if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        self.as_slice().get(index)
+    }
+
+    /// Returns the first element of the window, or [`None`] if it is empty.
+    pub fn first(&self) -> Option<&'a T> {
+        self.as_slice().first()
+    }
+
+    /// Returns the last element of the window, or [`None`] if it is empty.
+    pub fn last(&self) -> Option<&'a T> {
+        self.as_slice().last()
+    }
+
+    /// Returns `true` if the window contains an element with the given value.
+    pub fn contains(&self, x: &T) -> bool where T: PartialEq {
+        self.as_slice().contains(x)
+    }
+
+    /// Copies the window into a new `Vec`.
+    pub fn to_vec(&self) -> Vec<T> where T: Clone {
+        self.as_slice().to_vec()
+    }
+}
+
+impl<T, S: Sliceable<T>> Clone for VecSliceRef<'_, T, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, S: Sliceable<T>> Copy for VecSliceRef<'_, T, S> {}
+
+impl<T, S: Sliceable<T>, I: core::slice::SliceIndex<[T]>> core::ops::Index<I> for VecSliceRef<'_, T, S> {
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        &self.as_slice()[index]
+    }
+}
+
+impl<'a, T: 'a, S: Sliceable<T>> IntoIterator for VecSliceRef<'a, T, S> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: 'a, S: Sliceable<T>> IntoIterator for &VecSliceRef<'a, T, S> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, S: Sliceable<T>> From<&'a S> for VecSliceRef<'a, T, S> {
+    fn from(original: &'a S) -> Self {
+        Self::new(.., original)
+    }
+}
+
+impl<'a, T, S: Sliceable<T>> From<&'a VecSlice<'_, T, S>> for VecSliceRef<'a, T, S> {
+    fn from(slice: &'a VecSlice<'_, T, S>) -> Self {
+        slice.as_vecslice_ref()
+    }
+}
+
+impl<T: core::fmt::Debug, S: Sliceable<T>> core::fmt::Debug for VecSliceRef<'_, T, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("VecSliceRef").field("slice", &self.as_slice()).field("start", &self.start).field("end", &self.end).field("original_len", &self.original.len()).finish()
+    }
+}
+
+impl<T: PartialEq, S: Sliceable<T>> PartialEq for VecSliceRef<'_, T, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialOrd, S: Sliceable<T>> PartialOrd for VecSliceRef<'_, T, S> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: PartialEq, S: Sliceable<T>> PartialEq<VecSlice<'_, T, S>> for VecSliceRef<'_, T, S> {
+    fn eq(&self, other: &VecSlice<'_, T, S>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq, S: Sliceable<T>, const N: usize> PartialEq<[T; N]> for VecSliceRef<'_, T, S> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq, S: Sliceable<T>> PartialEq<&[T]> for VecSliceRef<'_, T, S> {
+    fn eq(&self, other: &&[T]) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<T: PartialEq, S: Sliceable<T>> PartialEq<Vec<T>> for VecSliceRef<'_, T, S> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T, S: Sliceable<T>> AsRef<[T]> for VecSliceRef<'_, T, S> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
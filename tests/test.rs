@@ -1,3 +1,5 @@
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+
 macro_rules! test {
     ( $( $(#[ $meta:meta ])* $name:ident => $code:expr )* ) => {
         $(
@@ -22,6 +24,7 @@ test! {
     new_tail1 => assert_eq!(vec![1].vecslice_at_tail(), [])
     new_tail2 => assert_eq!(vec![1, 2].vecslice_at_tail(), [])
     new_tail3 => assert_eq!(vec![1, 2, 3].vecslice_at_tail(), [])
+    new_at_tail_mid => assert_eq!(vec![1, 2, 3].vecslice(..1).new_at_tail(), [])
 
     new_range0_excluded0 => assert_eq!(vec![0; 0].vecslice(0..0), [])
     new_range0_excluded1 => assert_eq!(vec![1].vecslice(0..0), [])
@@ -55,4 +58,2328 @@ test! {
     new_range2_excluded3 => assert_eq!(vec![1, 2, 3].vecslice(0..2), [1, 2])
 
 
-}
\ No newline at end of file
+}
+test! {
+    overwrite_range_equal => {
+        let mut vec = vec![0, 1, 2, 3, 4];
+        let capacity = vec.capacity();
+        let mut slice = vec.vecslice(1..4);
+        slice.overwrite_range(.., &[7, 8, 9]);
+        assert_eq!(slice, [7, 8, 9]);
+        assert_eq!(vec, [0, 7, 8, 9, 4]);
+        assert_eq!(vec.capacity(), capacity);
+    }
+    overwrite_range_equal_in_place => {
+        let mut vec = vec![String::from("aaaa"), String::from("bbbb")];
+        let ptr = vec[1].as_ptr();
+        vec.vecslice(1..).overwrite_range(.., &[String::from("cc")]);
+        assert_eq!(vec, ["aaaa", "cc"]);
+        assert_eq!(vec[1].as_ptr(), ptr);
+    }
+    overwrite_range_longer => {
+        let mut vec = vec![0, 1, 2, 3, 4];
+        let mut slice = vec.vecslice(1..4);
+        slice.overwrite_range(1..2, &[7, 8, 9]);
+        assert_eq!(slice, [1, 7, 8, 9, 3]);
+        assert_eq!(vec, [0, 1, 7, 8, 9, 3, 4]);
+    }
+    overwrite_range_shorter => {
+        let mut vec = vec![0, 1, 2, 3, 4];
+        let mut slice = vec.vecslice(1..4);
+        slice.overwrite_range(..2, &[7]);
+        assert_eq!(slice, [7, 3]);
+        assert_eq!(vec, [0, 7, 3, 4]);
+    }
+    #[should_panic]
+    overwrite_range_out_of_bounds => vec![0, 1, 2, 3, 4].vecslice(1..3).overwrite_range(1..3, &[7, 8])
+}
+
+test! {
+    longest_increasing_run0 => assert_eq!(vec![0; 0].vecslice(..).longest_increasing_run(), 0..0)
+    longest_increasing_run1 => assert_eq!(vec![1].vecslice(..).longest_increasing_run(), 0..1)
+    longest_increasing_run_tie => assert_eq!(vec![1, 2, 0, 1, 0].vecslice(..).longest_increasing_run(), 0..2)
+    longest_increasing_run_equal => assert_eq!(vec![1, 1, 1].vecslice(..).longest_increasing_run(), 0..1)
+    longest_increasing_run_last => assert_eq!(vec![3, 2, 1, 2, 3].vecslice(..).longest_increasing_run(), 2..5)
+    longest_increasing_run_offset => assert_eq!(vec![0, 1, 2, 3, 1, 2, 3, 4, 5].vecslice(3..8).longest_increasing_run(), 1..5)
+}
+
+test! {
+    new_empty0 => assert_eq!(vec![0; 0].vecslice_empty(), [])
+    new_empty1 => assert_eq!(vec![1].vecslice_empty(), [])
+    new_empty2 => assert_eq!(vec![1, 2].vecslice_empty(), [])
+    new_empty3 => assert_eq!(vec![1, 2, 3].vecslice_empty(), [])
+}
+
+test! {
+    remove_indices_sequential => {
+        let mut expected = vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mut removed = expected.vecslice(2..9);
+        for i in [6, 4, 3, 1] {
+            removed.remove(i);
+        }
+        
+        let mut vec = vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mut slice = vec.vecslice(2..9);
+        slice.remove_indices(&[4, 1, 6, 3]);
+        assert_eq!(slice, removed);
+        assert_eq!(vec, expected);
+    }
+    remove_indices_empty => {
+        let mut vec = vec![0, 1, 2];
+        vec.vecslice(1..).remove_indices(&[]);
+        assert_eq!(vec, [0, 1, 2]);
+    }
+    remove_indices_all => {
+        let mut vec = vec![0, 1, 2, 3];
+        let mut slice = vec.vecslice(1..3);
+        slice.remove_indices(&[1, 0]);
+        assert_eq!(slice, []);
+        assert_eq!(vec, [0, 3]);
+    }
+    #[should_panic]
+    remove_indices_out_of_bounds => vec![0, 1, 2, 3].vecslice(1..3).remove_indices(&[2])
+}
+
+test! {
+    sliding_max_naive => {
+        let mut vec = vec![7, 3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, 9, 7];
+        let slice = vec.vecslice(2..13);
+        assert_eq!(slice.sliding_max(0), []);
+        for window in 1..=12 {
+            let naive: Vec<_> = slice.as_slice().windows(window).map(|w| *w.iter().max().unwrap()).collect();
+            assert_eq!(slice.sliding_max(window), naive);
+        }
+    }
+    sliding_min_naive => {
+        let mut vec = vec![7, 3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, 9, 7];
+        let slice = vec.vecslice(2..13);
+        assert_eq!(slice.sliding_min(0), []);
+        for window in 1..=12 {
+            let naive: Vec<_> = slice.as_slice().windows(window).map(|w| *w.iter().min().unwrap()).collect();
+            assert_eq!(slice.sliding_min(window), naive);
+        }
+    }
+}
+
+test! {
+    swap_disjoint_equal => {
+        let mut vec = vec![0, 1, 2, 3, 4, 5];
+        let mut slice = vec.vecslice(1..5);
+        slice.swap_disjoint(0..1, 3..4);
+        assert_eq!(slice, [4, 2, 3, 1]);
+        assert_eq!(vec, [0, 4, 2, 3, 1, 5]);
+    }
+    swap_disjoint_adjacent => {
+        let mut vec = vec![0, 1, 2, 3, 4, 5];
+        let mut slice = vec.vecslice(1..5);
+        slice.swap_disjoint(..1, 1..);
+        assert_eq!(slice, [2, 3, 4, 1]);
+        assert_eq!(vec, [0, 2, 3, 4, 1, 5]);
+    }
+    swap_disjoint_unequal => {
+        let mut vec = vec![0, 1, 2, 3, 4, 5, 6, 7];
+        let mut slice = vec.vecslice(1..7);
+        slice.swap_disjoint(4.., 0..3);
+        assert_eq!(slice, [5, 6, 4, 1, 2, 3]);
+        assert_eq!(vec, [0, 5, 6, 4, 1, 2, 3, 7]);
+    }
+    swap_disjoint_empty => {
+        let mut vec = vec![0, 1, 2, 3, 4];
+        let mut slice = vec.vecslice(1..4);
+        slice.swap_disjoint(0..0, 1..3);
+        assert_eq!(slice, [2, 3, 1]);
+    }
+    #[should_panic]
+    swap_disjoint_overlap => vec![0, 1, 2, 3, 4].vecslice(..).swap_disjoint(0..3, 2..4)
+    #[should_panic]
+    swap_disjoint_out_of_bounds => vec![0, 1, 2, 3, 4].vecslice(..3).swap_disjoint(0..1, 3..4)
+}
+
+test! {
+    delta_round_trip => {
+        let mut vec = vec![100, 3, -5, 8, 8, 13, 2, 100];
+        let mut slice = vec.vecslice(1..7);
+        slice.encode_delta();
+        assert_eq!(slice, [3, -8, 13, 0, 5, -11]);
+        slice.decode_delta();
+        assert_eq!(slice, [3, -5, 8, 8, 13, 2]);
+        assert_eq!(vec, [100, 3, -5, 8, 8, 13, 2, 100]);
+    }
+    delta_empty => {
+        let mut vec = vec![1, 2];
+        let mut slice = vec.vecslice(1..1);
+        slice.encode_delta();
+        slice.decode_delta();
+        assert_eq!(vec, [1, 2]);
+    }
+    delta_single => {
+        let mut vec = vec![1, 2, 3];
+        let mut slice = vec.vecslice(1..2);
+        slice.encode_delta();
+        assert_eq!(slice, [2]);
+        assert_eq!(vec, [1, 2, 3]);
+    }
+}
+
+test! {
+    ensure_len_grow => {
+        let mut vec = vec![0, 1, 2, 3, 4];
+        let mut slice = vec.vecslice(1..3);
+        slice.ensure_len(5, 7);
+        assert_eq!(slice, [1, 2, 7, 7, 7]);
+        assert_eq!(vec, [0, 1, 2, 7, 7, 7, 3, 4]);
+    }
+    ensure_len_shrink => {
+        let mut vec = vec![0, 1, 2, 3, 4];
+        let mut slice = vec.vecslice(1..4);
+        slice.ensure_len(1, 7);
+        assert_eq!(slice, [1]);
+        assert_eq!(vec, [0, 1, 4]);
+    }
+    ensure_len_same => {
+        let mut vec = vec![0, 1, 2, 3, 4];
+        let mut slice = vec.vecslice(1..4);
+        slice.ensure_len(3, 7);
+        assert_eq!(slice, [1, 2, 3]);
+        assert_eq!(vec, [0, 1, 2, 3, 4]);
+    }
+}
+
+test! {
+    clear_keeps_surroundings => {
+        let mut vec: Vec<_> = (0..10_000).collect();
+        let mut slice = vec.vecslice(1..9_999);
+        slice.clear();
+        assert!(slice.is_empty());
+        assert_eq!(vec, [0, 9_999]);
+    }
+}
+
+test! {
+    min_max_even => assert_eq!(vec![9, 2, 8, 1, 7, 9].vecslice(1..5).min_max(), Some((&1, &8)))
+    min_max_odd => assert_eq!(vec![9, 2, 8, 1, 7, 0].vecslice(1..4).min_max(), Some((&1, &8)))
+    min_max_ties => {
+        let mut vec = vec![1, 0, 2, 0, 2, 1];
+        let slice = vec.vecslice(..5);
+        let (min, max) = slice.min_max().unwrap();
+        assert!(core::ptr::eq(min, &slice[1]));
+        assert!(core::ptr::eq(max, &slice[4]));
+    }
+}
+
+test! {
+    merge_sorted_with_front => {
+        let mut vec = vec![3, 4, 5, 0];
+        let mut slice = vec.vecslice(..3);
+        slice.merge_sorted_with(&[1, 2]);
+        assert_eq!(slice, [1, 2, 3, 4, 5]);
+        assert_eq!(vec, [1, 2, 3, 4, 5, 0]);
+    }
+    merge_sorted_with_middle => {
+        let mut vec = vec![9, 1, 5, 9, 0];
+        let mut slice = vec.vecslice(1..4);
+        slice.merge_sorted_with(&[3, 7, 10]);
+        assert_eq!(slice, [1, 3, 5, 7, 9, 10]);
+        assert_eq!(vec, [9, 1, 3, 5, 7, 9, 10, 0]);
+    }
+    merge_sorted_with_duplicates => {
+        let mut vec = vec![(0, 'x'), (1, 'a'), (2, 'a'), (2, 'b'), (0, 'y')];
+        let mut slice = vec.vecslice(1..4);
+        slice.merge_sorted_with(&[(1, 'a'), (2, 'a'), (2, 'c')]);
+        assert_eq!(slice, [(1, 'a'), (1, 'a'), (2, 'a'), (2, 'a'), (2, 'b'), (2, 'c')]);
+        assert_eq!(vec.first(), Some(&(0, 'x')));
+        assert_eq!(vec.last(), Some(&(0, 'y')));
+    }
+    merge_sorted_with_empty => {
+        let mut vec = vec![9, 0];
+        let mut slice = vec.vecslice(1..1);
+        slice.merge_sorted_with(&[1, 2]);
+        assert_eq!(slice, [1, 2]);
+        slice.merge_sorted_with(&[]);
+        assert_eq!(vec, [9, 1, 2, 0]);
+    }
+}
+
+test! {
+    insert_sorted_all_unsorted => {
+        let mut vec = vec![100, 2, 4, 6, 8, -100];
+        let mut slice = vec.vecslice(1..5);
+        slice.insert_sorted_all(&[9, 1, 5, 5, 3]);
+        assert_eq!(slice, [1, 2, 3, 4, 5, 5, 6, 8, 9]);
+        assert_eq!(vec, [100, 1, 2, 3, 4, 5, 5, 6, 8, 9, -100]);
+    }
+    insert_sorted_all_empty_slice => {
+        let mut vec = vec![100, -100];
+        let mut slice = vec.vecslice(1..1);
+        slice.insert_sorted_all(&[3, 1, 2]);
+        assert_eq!(slice, [1, 2, 3]);
+        assert_eq!(vec, [100, 1, 2, 3, -100]);
+    }
+}
+
+test! {
+    filter_map_in_place_mid => {
+        let mut vec = vec![-1, 1, 2, 3, 4, 5, 6, -1];
+        let mut slice = vec.vecslice(1..7);
+        slice.filter_map_in_place(|x| (x % 2 == 0).then_some(x * 2));
+        assert_eq!(slice, [4, 8, 12]);
+        assert_eq!(vec, [-1, 4, 8, 12, -1]);
+    }
+    filter_map_in_place_none => {
+        let mut vec = vec![-1, 1, 2, -1];
+        let mut slice = vec.vecslice(1..3);
+        slice.filter_map_in_place(|_| None);
+        assert_eq!(slice, []);
+        assert_eq!(vec, [-1, -1]);
+    }
+    filter_map_in_place_panic => {
+        let mut vec: Vec<String> = ["a", "b", "c", "d", "e", "f"].map(String::from).into();
+        let mut slice = vec.vecslice(1..5);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            slice.filter_map_in_place(|x| match x.as_str() {
+                "b" => None,
+                "d" => panic!(),
+                _ => Some(x.to_uppercase()),
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(slice.as_slice(), ["C", "e"]);
+        slice.push_back(String::from("g"));
+        assert_eq!(vec, ["a", "C", "e", "g", "f"]);
+    }
+}
+
+/// Backend that only implements the required [`Sliceable`] methods, to test the default implementations.
+struct Naive(Vec<i32>);
+
+impl vecslice::Sliceable<i32> for Naive {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn insert(&mut self, index: usize, element: i32) {
+        self.0.insert(index, element);
+    }
+
+    fn remove(&mut self, index: usize) -> i32 {
+        self.0.remove(index)
+    }
+
+    fn as_slice(&self) -> &[i32] {
+        &self.0
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [i32] {
+        &mut self.0
+    }
+}
+
+test! {
+    extend => {
+        let mut vec = vec![0, 1, 2, 3];
+        let mut slice = vec.vecslice(1..3);
+        slice.extend([7, 8]);
+        assert_eq!(slice, [1, 2, 7, 8]);
+        assert_eq!(vec, [0, 1, 2, 7, 8, 3]);
+    }
+    drain_partial => {
+        let mut vec = vec![0, 1, 2, 3, 4, 5, 6];
+        let mut slice = vec.vecslice(1..6);
+        let mut drain = slice.drain(1..4);
+        assert_eq!(drain.len(), 3);
+        assert_eq!(drain.next(), Some(2));
+        drop(drain);
+        assert_eq!(slice, [1, 5]);
+        assert_eq!(vec, [0, 1, 5, 6]);
+    }
+    drain_empty => {
+        let mut vec = vec![0, 1, 2];
+        let mut slice = vec.vecslice(1..);
+        assert_eq!(slice.drain(1..1).count(), 0);
+        assert_eq!(slice, [1, 2]);
+        assert_eq!(vec, [0, 1, 2]);
+    }
+    naive_backend => {
+        let mut naive = Naive(vec![0, 1, 2, 3, 4, 5]);
+        let mut slice = naive.vecslice(1..5);
+        slice.extend([6, 7]);
+        assert_eq!(slice, [1, 2, 3, 4, 6, 7]);
+        assert_eq!(slice.drain(1..3).collect::<Vec<_>>(), [2, 3]);
+        slice.overwrite_range(..1, &[8, 9]);
+        assert_eq!(slice, [8, 9, 4, 6, 7]);
+        assert_eq!(slice.remaining_capacity(), None);
+        let mut tail = slice.new_at_tail();
+        tail.push_back(10);
+        tail.clear();
+        assert_eq!(naive.0, [0, 8, 9, 4, 6, 7, 5]);
+    }
+}
+
+test! {
+    split_at_edges => {
+        let mut vec = vec![0, 1, 2, 3];
+        let mut split = vec.vecslice(1..3).split_at(0);
+        assert_eq!(split.left(), []);
+        split.left().push_back(4);
+        assert_eq!(split.right(), [1, 2]);
+        split.right().pop_back();
+        split.right().pop_back();
+        split.right().push_back(5);
+        assert_eq!(split.left(), [4]);
+        assert_eq!(split.join(), [4, 5]);
+        assert_eq!(vec, [0, 4, 5, 3]);
+    }
+    split_at_mut_shrink => {
+        let mut vec = vec![0, 1, 2, 3, 4, 5];
+        let mut slice = vec.vecslice(1..5);
+        let mut split = slice.split_at_mut(2);
+        split.left().clear();
+        split.right().remove(0);
+        split.left().push_back(6);
+        drop(split);
+        assert_eq!(slice, [6, 4]);
+        slice.push_back(7);
+        assert_eq!(vec, [0, 6, 4, 7, 5]);
+    }
+    #[should_panic]
+    split_at_out_of_bounds => drop(vec![0, 1, 2].vecslice(..2).split_at(3))
+    split_half_keeps_neighbour => {
+        let mut vec = vec![0, 1, 2, 3];
+        let mut split = vec.vecslice(..).split_at(2);
+        let removed = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| split.left().remove(2)));
+        assert!(removed.is_err());
+        let drained = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| drop(split.left().drain(..4))));
+        assert!(drained.is_err());
+        assert_eq!(split.left(), [0, 1]);
+        assert_eq!(split.right(), [2, 3]);
+        drop(split);
+        assert_eq!(vec, [0, 1, 2, 3]);
+    }
+    #[should_panic]
+    remove_at_len => vec![0, 1, 2].vecslice(..2).remove(2)
+    #[should_panic]
+    drain_past_end => drop(vec![0, 1, 2].vecslice(..2).drain(1..3))
+}
+
+use vecslice::VecSliceError;
+
+test! {
+    try_new_ok => assert_eq!(vec![1, 2, 3].try_vecslice(1..=2).unwrap(), [2, 3])
+    try_new_empty => assert_eq!(vec![0; 0].try_vecslice(..).unwrap(), [])
+    try_new_start => assert_eq!(vec![1, 2, 3].try_vecslice(4..).unwrap_err(), VecSliceError::StartOutOfBounds { start: 4, len: 3 })
+    try_new_end => assert_eq!(vec![1, 2, 3].try_vecslice(..=3).unwrap_err(), VecSliceError::EndOutOfBounds { end: 4, len: 3 })
+    #[allow(clippy::reversed_empty_ranges)]
+    try_new_inverted => assert_eq!(vec![1, 2, 3].try_vecslice(2..1).unwrap_err(), VecSliceError::InvertedRange { start: 2, end: 1 })
+    try_new_overflow => assert_eq!(vec![1, 2, 3].try_vecslice(..=usize::MAX).unwrap_err(), VecSliceError::EndOutOfBounds { end: usize::MAX, len: 3 })
+    try_drain_relative => {
+        let mut vec = vec![0, 1, 2, 3];
+        let mut slice = vec.vecslice(1..3);
+        assert_eq!(slice.try_drain(..3).unwrap_err(), VecSliceError::EndOutOfBounds { end: 3, len: 2 });
+        assert_eq!(slice, [1, 2]);
+    }
+    try_remove_empty => assert_eq!(vec![1].vecslice(1..).try_remove(0), Err(VecSliceError::IndexOutOfBounds { index: 0, len: 0 }))
+    error_display => assert_eq!(VecSliceError::InvertedRange { start: 2, end: 1 }.to_string(), "slice index starts at 2 but ends at 1")
+}
+
+test! {
+    retain_all => {
+        let mut vec = vec![1, 2, 3, 4];
+        let mut slice = vec.vecslice(1..3);
+        slice.retain(|_| true);
+        assert_eq!(vec, [1, 2, 3, 4]);
+    }
+    retain_none => {
+        let mut vec = vec![1, 2, 3, 4];
+        let mut slice = vec.vecslice(1..3);
+        slice.retain(|_| false);
+        assert_eq!(slice, []);
+        assert_eq!(vec, [1, 4]);
+    }
+    retain_order => {
+        let mut vec: Vec<_> = (0..20).collect();
+        let mut slice = vec.vecslice(5..15);
+        let mut visited = Vec::new();
+        slice.retain(|&x| {
+            visited.push(x);
+            x % 3 != 0
+        });
+        assert_eq!(visited, (5..15).collect::<Vec<_>>());
+        assert_eq!(slice, [5, 7, 8, 10, 11, 13, 14]);
+        assert_eq!(vec.len(), 17);
+    }
+}
+
+test! {
+    extract_if_early_drop => {
+        let mut vec = vec![0, 1, 2, 3, 4, 5, 6, 7];
+        let mut slice = vec.vecslice(1..7);
+        assert_eq!(slice.extract_if(|x| *x % 2 == 0).next(), Some(2));
+        assert_eq!(slice, [1, 3, 4, 5, 6]);
+        slice.push_back(8);
+        assert_eq!(vec, [0, 1, 3, 4, 5, 6, 8, 7]);
+    }
+    extract_if_mutate => {
+        let mut vec = vec![0, 1, 2, 3];
+        let mut slice = vec.vecslice(..3);
+        let extracted: Vec<_> = slice.extract_if(|x| {
+            *x *= 10;
+            *x == 10
+        }).collect();
+        assert_eq!(extracted, [10]);
+        assert_eq!(vec, [0, 20, 3]);
+    }
+}
+
+test! {
+    splice_equal => {
+        let mut vec = vec![0, 1, 2, 3];
+        let mut slice = vec.vecslice(1..3);
+        assert_eq!(slice.splice(.., [5, 6]).collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(vec, [0, 5, 6, 3]);
+    }
+    splice_insert => {
+        let mut vec = vec![0, 1, 2, 3];
+        let mut slice = vec.vecslice(1..3);
+        assert_eq!(slice.splice(1..1, [5, 6]).count(), 0);
+        assert_eq!(slice, [1, 5, 6, 2]);
+        assert_eq!(vec, [0, 1, 5, 6, 2, 3]);
+    }
+    splice_remove => {
+        let mut vec = vec![0, 1, 2, 3];
+        let mut slice = vec.vecslice(1..3);
+        assert_eq!(slice.splice(.., []).collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(slice, []);
+        assert_eq!(vec, [0, 3]);
+    }
+    #[should_panic]
+    splice_out_of_bounds => drop(vec![0, 1, 2, 3].vecslice(1..3).splice(1..3, [1]))
+}
+
+test! {
+    truncate0 => {
+        let mut vec = vec![0, 1, 2, 3];
+        let mut slice = vec.vecslice(1..3);
+        slice.truncate(0);
+        assert_eq!(slice, []);
+        assert_eq!(vec, [0, 3]);
+    }
+    truncate_front0 => {
+        let mut vec = vec![0, 1, 2, 3];
+        let mut slice = vec.vecslice(1..3);
+        slice.truncate_front(0);
+        assert_eq!(slice, []);
+        assert_eq!(vec, [0, 3]);
+    }
+    truncate_naive => {
+        let mut naive = Naive(vec![0, 1, 2, 3, 4, 5]);
+        let mut slice = naive.vecslice(1..5);
+        slice.truncate(3);
+        slice.truncate_front(1);
+        assert_eq!(slice, [3]);
+        assert_eq!(naive.0, [0, 3, 5]);
+    }
+}
+
+test! {
+    split_off_all => {
+        let mut vec = vec![0, 1, 2, 3];
+        let mut slice = vec.vecslice(1..3);
+        assert_eq!(slice.split_off(0), [1, 2]);
+        assert_eq!(slice, []);
+        assert_eq!(vec, [0, 3]);
+    }
+    split_off_none => {
+        let mut vec = vec![0, 1, 2, 3];
+        let mut slice = vec.vecslice(1..3);
+        assert_eq!(slice.split_off(2), []);
+        assert_eq!(vec, [0, 1, 2, 3]);
+    }
+    #[should_panic]
+    split_off_out_of_bounds => vec![0, 1, 2, 3].vecslice(1..3).split_off(3)
+}
+
+test! {
+    append_empty => {
+        let mut vec = vec![0, 1, 2];
+        let mut slice = vec.vecslice(1..2);
+        slice.append(&mut Vec::new());
+        assert_eq!(slice, [1]);
+        assert_eq!(vec, [0, 1, 2]);
+    }
+    append_naive => {
+        let mut naive = Naive(vec![0, 1, 2]);
+        let mut slice = naive.vecslice(0..1);
+        let mut other = vec![3, 4];
+        slice.append(&mut other);
+        assert_eq!(slice, [0, 3, 4]);
+        assert!(other.is_empty());
+        assert_eq!(naive.0, [0, 3, 4, 1, 2]);
+    }
+}
+
+test! {
+    extend_from_within_empty => {
+        let mut vec = vec![0, 1, 2];
+        let mut slice = vec.vecslice(1..2);
+        slice.extend_from_within(1..);
+        assert_eq!(slice, [1]);
+        assert_eq!(vec, [0, 1, 2]);
+    }
+    #[should_panic]
+    extend_from_within_out_of_bounds => vec![0, 1, 2].vecslice(0..2).extend_from_within(1..3)
+}
+
+test! {
+    resize_same => {
+        let mut vec = vec![0, 1, 2];
+        let mut slice = vec.vecslice(1..2);
+        slice.resize(1, 9);
+        assert_eq!(slice, [1]);
+        assert_eq!(vec, [0, 1, 2]);
+    }
+    resize_with_naive => {
+        let mut naive = Naive(vec![0, 1, 2]);
+        let mut slice = naive.vecslice(1..2);
+        slice.resize_with(3, Default::default);
+        assert_eq!(slice, [1, 0, 0]);
+        slice.resize_with(0, || unreachable!());
+        assert_eq!(slice, []);
+        assert_eq!(naive.0, [0, 2]);
+    }
+}
+
+test! {
+    dedup_empty => {
+        let mut vec = vec![1, 1];
+        let mut slice = vec.vecslice(1..1);
+        slice.dedup();
+        assert_eq!(slice, []);
+        assert_eq!(vec, [1, 1]);
+    }
+    dedup_boundary => {
+        // Duplicates across the slice boundaries are kept
+        let mut vec = vec![1, 1, 1, 1];
+        let mut slice = vec.vecslice(1..3);
+        slice.dedup();
+        assert_eq!(slice, [1]);
+        assert_eq!(vec, [1, 1, 1]);
+    }
+    dedup_by_order => {
+        let mut vec = vec![0, 1, 2, 3, 10, 11];
+        let mut slice = vec.vecslice(..);
+        // Compares against the first element of each run
+        slice.dedup_by(|a, b| *a - *b < 2);
+        assert_eq!(slice, [0, 2, 10]);
+    }
+}
+
+test! {
+    swap_remove_last => {
+        let mut vec = vec![0, 1, 2, 3];
+        let mut slice = vec.vecslice(1..3);
+        assert_eq!(slice.swap_remove(1), 2);
+        assert_eq!(slice.swap_remove(0), 1);
+        assert_eq!(slice, []);
+        assert_eq!(vec, [0, 3]);
+    }
+    swap_remove_front_first => {
+        let mut vec = vec![0, 1, 2, 3];
+        let mut slice = vec.vecslice(1..3);
+        assert_eq!(slice.swap_remove_front(0), 1);
+        assert_eq!(slice, [2]);
+        assert_eq!(vec, [0, 2, 3]);
+    }
+    #[should_panic]
+    swap_remove_out_of_bounds => vec![0, 1, 2].vecslice(0..2).swap_remove(2)
+    #[should_panic]
+    swap_remove_front_out_of_bounds => vec![0, 1, 2].vecslice(0..2).swap_remove_front(2)
+}
+
+test! {
+    rotate_full => {
+        let mut vec = vec![0, 1, 2, 3];
+        let mut slice = vec.vecslice(1..3);
+        slice.rotate_left(2);
+        assert_eq!(slice, [1, 2]);
+        slice.rotate_right(0);
+        assert_eq!(vec, [0, 1, 2, 3]);
+    }
+    #[should_panic]
+    rotate_left_out_of_bounds => vec![0, 1, 2].vecslice(0..2).rotate_left(3)
+    #[should_panic]
+    rotate_right_out_of_bounds => vec![0, 1, 2].vecslice(0..2).rotate_right(3)
+}
+
+test! {
+    reverse_empty => {
+        let mut vec = vec![0, 1];
+        vec.vecslice(1..1).reverse();
+        assert_eq!(vec, [0, 1]);
+    }
+}
+
+test! {
+    swap_same => {
+        let mut vec = vec![0, 1, 2];
+        vec.vecslice(1..).swap(1, 1);
+        assert_eq!(vec, [0, 1, 2]);
+    }
+    // Index 2 exists in the vector but not in the slice
+    #[should_panic]
+    swap_out_of_bounds => vec![0, 1, 2].vecslice(0..2).swap(0, 2)
+    #[should_panic]
+    swap_with_slice_len_mismatch => vec![0, 1, 2].vecslice(0..2).swap_with_slice(&mut [0, 1, 2])
+}
+
+test! {
+    get_relative => {
+        let mut vec = vec![0, 1, 2, 3];
+        let mut slice = vec.vecslice(1..3);
+        assert_eq!(slice.get(0), Some(&1));
+        assert_eq!(slice.get_mut(2), None);
+        assert_eq!(slice.first(), Some(&1));
+        assert_eq!(slice.last(), Some(&2));
+    }
+    get_empty => {
+        let mut vec = vec![0, 1];
+        let mut slice = vec.vecslice(1..1);
+        assert_eq!(slice.get(0), None);
+        assert_eq!(slice.first_mut(), None);
+        assert_eq!(slice.last_mut(), None);
+    }
+}
+
+test! {
+    index_usize => {
+        let mut vec = vec![0, 1, 2, 3];
+        let mut slice = vec.vecslice(1..3);
+        assert_eq!(slice[1], 2);
+        slice[0] = 5;
+        assert_eq!((&slice)[0], 5);
+        assert_eq!(vec, [0, 5, 2, 3]);
+    }
+    // Index 2 exists in the vector but not in the slice
+    #[should_panic]
+    index_usize_out_of_bounds => vec![0, 1, 2].vecslice(0..2)[2]
+    #[should_panic]
+    index_mut_usize_out_of_bounds => vec![0, 1, 2].vecslice(0..2)[2] = 0
+}
+
+test! {
+    binary_search_ignores_outside => {
+        // Elements outside the slice are never considered
+        let mut vec = vec![0, 5, 6, 1];
+        let slice = vec.vecslice(1..3);
+        assert_eq!(slice.binary_search(&0), Err(0));
+        assert_eq!(slice.binary_search(&1), Err(0));
+        assert_eq!(slice.binary_search(&6), Ok(1));
+        assert_eq!(slice.binary_search(&7), Err(2));
+    }
+    partition_point_empty => {
+        let mut vec = vec![0, 1];
+        assert_eq!(vec.vecslice(1..1).partition_point(|_| true), 0);
+    }
+}
+
+test! {
+    insert_sorted_empty => {
+        let mut vec = vec![9, 0];
+        let mut slice = vec.vecslice(1..1);
+        assert_eq!(slice.insert_sorted(5), 0);
+        assert_eq!(vec, [9, 5, 0]);
+    }
+    insert_sorted_end => {
+        let mut vec = vec![1, 2, 0];
+        let mut slice = vec.vecslice(..2);
+        assert_eq!(slice.insert_sorted(2), 2);
+        assert_eq!(slice, [1, 2, 2]);
+        assert_eq!(vec, [1, 2, 2, 0]);
+    }
+}
+
+test! {
+    sort_by_key_stable => {
+        let mut vec = vec![(9, 'z'), (1, 'a'), (0, 'b'), (1, 'c'), (0, 'd'), (9, 'y')];
+        let mut slice = vec.vecslice(1..5);
+        slice.sort_by_key(|&(n, _)| n);
+        assert_eq!(slice, [(0, 'b'), (0, 'd'), (1, 'a'), (1, 'c')]);
+        slice.sort_by_cached_key(|&(_, c)| core::cmp::Reverse(c));
+        assert_eq!(vec, [(9, 'z'), (0, 'd'), (1, 'c'), (0, 'b'), (1, 'a'), (9, 'y')]);
+    }
+}
+
+test! {
+    select_nth_unstable_partition => {
+        let mut vec = vec![-1, 5, 3, 8, 1, 7, 10];
+        let mut slice = vec.vecslice(1..6);
+        let (before, nth, after) = slice.select_nth_unstable(3);
+        assert_eq!(*nth, 7);
+        assert!(before.iter().all(|&x| x <= 7));
+        assert_eq!(after, [8]);
+        assert_eq!((vec[0], vec[6]), (-1, 10));
+    }
+    // Index 2 exists in the vector but not in the slice
+    #[should_panic]
+    select_nth_unstable_out_of_bounds => { vec![0, 1, 2].vecslice(0..2).select_nth_unstable(2); }
+}
+
+test! {
+    contains_empty => {
+        let mut vec = vec![0, 1];
+        let slice = vec.vecslice(1..1);
+        assert!(!slice.contains(&1));
+        assert!(slice.starts_with(&[]));
+        assert!(!slice.ends_with(&[0]));
+    }
+}
+
+test! {
+    strip_prefix_tokens => {
+        let mut input = b"let x = 1;".to_vec();
+        let mut slice = input.vecslice(..);
+        assert!(slice.strip_prefix(b"let "));
+        assert!(slice.strip_suffix(b";"));
+        assert!(!slice.strip_prefix(b"let "));
+        assert_eq!(slice, *b"x = 1");
+    }
+    strip_prefix_empty => {
+        let mut vec = vec![0, 1, 2];
+        let mut slice = vec.vecslice(1..2);
+        assert!(slice.strip_prefix(&[]));
+        assert!(slice.strip_suffix(&[1]));
+        assert!(!slice.strip_suffix(&[1]));
+        assert_eq!(vec, [0, 2]);
+    }
+    // The element before the slice is not part of the prefix
+    strip_prefix_outside => {
+        let mut vec = vec![0, 1, 2];
+        let mut slice = vec.vecslice(1..);
+        assert!(!slice.strip_prefix(&[0, 1]));
+        assert_eq!(vec, [0, 1, 2]);
+    }
+}
+
+test! {
+    pop_if_empty => {
+        let mut vec = vec![0, 1];
+        let mut slice = vec.vecslice(1..1);
+        assert_eq!(slice.pop_back_if(|_| true), None);
+        assert_eq!(slice.pop_front_if(|_| true), None);
+        assert_eq!(vec, [0, 1]);
+    }
+    pop_if_mutate => {
+        let mut vec = vec![0, 1, 2];
+        let mut slice = vec.vecslice(..2);
+        assert_eq!(slice.pop_back_if(|x| { *x += 10; false }), None);
+        assert_eq!(vec, [0, 11, 2]);
+    }
+}
+
+test! {
+    pop_n_zero => {
+        let mut vec = vec![0, 1, 2];
+        let mut slice = vec.vecslice(1..);
+        assert_eq!(slice.pop_back_n(0), []);
+        assert_eq!(slice.pop_front_n(0), []);
+        assert_eq!(vec, [0, 1, 2]);
+    }
+    pop_n_naive => {
+        let mut naive = Naive(vec![0, 1, 2, 3, 4, 5]);
+        let mut slice = naive.vecslice(1..5);
+        assert_eq!(slice.pop_front_n(2), [1, 2]);
+        assert_eq!(slice.pop_back_n(1), [4]);
+        assert_eq!(slice, [3]);
+        assert_eq!(naive.0, [0, 3, 5]);
+    }
+}
+
+test! {
+    extend_front_empty_slice => {
+        let mut vec = vec![0, 1];
+        let mut slice = vec.vecslice(1..1);
+        slice.extend_front(2..5);
+        assert_eq!(slice, [2, 3, 4]);
+        slice.extend_front(None);
+        assert_eq!(vec, [0, 2, 3, 4, 1]);
+    }
+    extend_front_naive => {
+        let mut naive = Naive(vec![0, 1]);
+        let mut slice = naive.vecslice(1..);
+        slice.extend_front([2, 3]);
+        assert_eq!(slice, [2, 3, 1]);
+        assert_eq!(naive.0, [0, 2, 3, 1]);
+    }
+}
+
+test! {
+    insert_many_front => {
+        let mut vec = vec![0, 1];
+        let mut slice = vec.vecslice(1..);
+        slice.insert_many(0, [2, 3]);
+        assert_eq!(slice, [2, 3, 1]);
+        assert_eq!(vec, [0, 2, 3, 1]);
+    }
+    insert_slice_naive => {
+        let mut naive = Naive(vec![0, 1, 2]);
+        let mut slice = naive.vecslice(..2);
+        slice.insert_slice(1, &[3, 4]);
+        assert_eq!(slice, [0, 3, 4, 1]);
+        assert_eq!(naive.0, [0, 3, 4, 1, 2]);
+    }
+    // Index 3 exists in the vector but not in the slice
+    #[should_panic]
+    insert_many_out_of_bounds => vec![0, 1, 2, 3].vecslice(1..3).insert_many(3, [4])
+}
+
+/// Backend that counts how many elements have been shifted by `insert` and `remove`.
+struct Shifts(Vec<i32>, usize);
+
+impl vecslice::Sliceable<i32> for Shifts {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn insert(&mut self, index: usize, element: i32) {
+        self.1 += self.0.len() - index;
+        self.0.insert(index, element);
+    }
+
+    fn remove(&mut self, index: usize) -> i32 {
+        self.1 += self.0.len() - index - 1;
+        self.0.remove(index)
+    }
+
+    fn as_slice(&self) -> &[i32] {
+        &self.0
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [i32] {
+        &mut self.0
+    }
+}
+
+test! {
+    drain_no_shifts => {
+        let mut shifts = Shifts((0..100).collect(), 0);
+        let mut slice = shifts.vecslice(10..90);
+        let mut drain = slice.drain(10..70);
+        assert_eq!(drain.by_ref().take(30).collect::<Vec<_>>(), (20..50).collect::<Vec<_>>());
+        assert_eq!(drain.len(), 30);
+        drop(drain);
+        assert_eq!(slice.len(), 20);
+        assert_eq!(shifts.0, (0..20).chain(80..100).collect::<Vec<_>>());
+        assert_eq!(shifts.1, 0);
+    }
+    clear_range_shifts_tail => {
+        let mut shifts = Shifts((0..10).collect(), 0);
+        shifts.vecslice(2..6).clear();
+        assert_eq!(shifts.0, [0, 1, 6, 7, 8, 9]);
+        // Only the 4 elements after the range are shifted, once per removed element
+        assert_eq!(shifts.1, 4 * 4);
+    }
+}
+
+/// Backend with bulk methods like [`Vec`], that counts how many times the elements after the range it is given are moved.
+struct Moves(Vec<i32>, usize);
+
+impl Moves {
+    fn count(&mut self, end: usize) {
+        if end < self.0.len() {
+            self.1 += 1;
+        }
+    }
+}
+
+impl vecslice::Sliceable<i32> for Moves {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn insert(&mut self, index: usize, element: i32) {
+        self.count(index);
+        self.0.insert(index, element);
+    }
+
+    fn remove(&mut self, index: usize) -> i32 {
+        self.count(index + 1);
+        self.0.remove(index)
+    }
+
+    fn as_slice(&self) -> &[i32] {
+        &self.0
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [i32] {
+        let len = self.0.len();
+        self.as_mut_range(0, len)
+    }
+
+    fn as_mut_range(&mut self, start: usize, end: usize) -> &mut [i32] {
+        // Only a reordering can move the elements after the range, which is always the case of a range up to the end
+        if end == self.0.len() && start < end {
+            self.1 += 1;
+        }
+        &mut self.0[start..end]
+    }
+
+    fn clear_range(&mut self, start: usize, end: usize) {
+        if start < end {
+            self.count(end);
+        }
+        self.0.drain(start..end);
+    }
+
+    fn splice_range<I: IntoIterator<Item = i32>>(&mut self, start: usize, end: usize, replace_with: I) -> usize {
+        let len = self.0.len();
+        self.0.splice(start..end, replace_with);
+        let inserted = self.0.len() + (end - start) - len;
+        if inserted != end - start {
+            self.count(end);
+        }
+        inserted
+    }
+}
+
+test! {
+    insert_sorted_all_moves_once => {
+        let mut moves = Moves(vec![0, 1, 3, 5, 7, 8, 9], 0);
+        let mut slice = moves.vecslice(1..4);
+        slice.insert_sorted_all(&[6, 0, 4]);
+        assert_eq!(slice, [0, 1, 3, 4, 5, 6]);
+        assert_eq!(moves.0, [0, 0, 1, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(moves.1, 1);
+    }
+    merge_sorted_with_moves_once => {
+        let mut moves = Moves(vec![9, 1, 3, 5, 0], 0);
+        let mut slice = moves.vecslice(1..4);
+        slice.merge_sorted_with(&[2, 3, 6]);
+        assert_eq!(slice, [1, 2, 3, 3, 5, 6]);
+        assert_eq!(moves.0, [9, 1, 2, 3, 3, 5, 6, 0]);
+        assert_eq!(moves.1, 1);
+    }
+    ensure_len_moves_once => {
+        let mut moves = Moves(vec![0, 1, 2, 3], 0);
+        moves.vecslice(1..3).ensure_len(5, 9);
+        assert_eq!(moves.0, [0, 1, 2, 9, 9, 9, 3]);
+        assert_eq!(moves.1, 1);
+        moves.vecslice(1..6).ensure_len(1, 9);
+        assert_eq!(moves.0, [0, 1, 3]);
+        assert_eq!(moves.1, 2);
+        moves.vecslice(1..2).ensure_len(1, 9);
+        assert_eq!(moves.1, 2);
+    }
+}
+
+test! {
+    drain_rev => {
+        let mut vec = vec![0, 1, 2, 3, 4, 5];
+        let mut slice = vec.vecslice(1..5);
+        assert_eq!(slice.drain(1..).rev().collect::<Vec<_>>(), [4, 3, 2]);
+        assert_eq!(vec, [0, 1, 5]);
+    }
+    drain_both_ends => {
+        let mut vec = vec![0, 1, 2, 3, 4, 5];
+        let mut slice = vec.vecslice(..);
+        let mut drain = slice.drain(1..5);
+        assert_eq!(drain.size_hint(), (4, Some(4)));
+        assert_eq!(drain.next_back(), Some(4));
+        assert_eq!(drain.next(), Some(1));
+        assert_eq!(drain.len(), 2);
+        assert_eq!(drain.next_back(), Some(3));
+        assert_eq!(drain.next_back(), Some(2));
+        assert_eq!(drain.next(), None);
+        assert_eq!(drain.next_back(), None);
+        assert_eq!(drain.size_hint(), (0, Some(0)));
+        drop(drain);
+        assert_eq!(vec, [0, 5]);
+    }
+}
+
+test! {
+    drain_keep_rest_untouched => {
+        let mut vec = vec![0, 1, 2, 3];
+        let mut slice = vec.vecslice(1..3);
+        slice.drain(..).keep_rest();
+        assert_eq!(slice, [1, 2]);
+        slice.push_back(4);
+        assert_eq!(vec, [0, 1, 2, 4, 3]);
+    }
+    drain_keep_rest_naive => {
+        let mut naive = Naive(vec![0, 1, 2, 3, 4, 5]);
+        let mut slice = naive.vecslice(1..5);
+        let mut drain = slice.drain(1..);
+        assert_eq!(drain.next_back(), Some(4));
+        assert_eq!(drain.as_slice(), [2, 3]);
+        drain.keep_rest();
+        assert_eq!(slice, [1, 2, 3]);
+        assert_eq!(naive.0, [0, 1, 2, 3, 5]);
+    }
+}
+
+test! {
+    into_iter_owned => {
+        let mut vec = vec![0, 1, 2, 3];
+        let mut sum = 0;
+        for x in vec.vecslice(1..3) {
+            sum += x;
+        }
+        assert_eq!(sum, 3);
+        assert_eq!(vec, [0, 3]);
+    }
+    into_iter_partial => {
+        // Elements that are not consumed are dropped as well
+        let mut vec = vec![0, 1, 2, 3];
+        assert_eq!(vec.vecslice(1..3).into_iter().next_back(), Some(2));
+        assert_eq!(vec, [0, 3]);
+    }
+}
+
+test! {
+    iter_absolute_rev => {
+        let mut vec = vec![0, 1, 2, 3, 4];
+        let slice = vec.vecslice(1..4);
+        assert_eq!(slice.iter_absolute().rev().collect::<Vec<_>>(), [(3, &3), (2, &2), (1, &1)]);
+        assert_eq!(slice.iter_absolute().len(), 3);
+    }
+}
+
+test! {
+    chunks_empty => {
+        let mut vec = vec![0, 1];
+        let slice = vec.vecslice(1..1);
+        assert_eq!(slice.chunks(2).count(), 0);
+        assert_eq!(slice.windows(1).count(), 0);
+    }
+    windows_longer_than_slice => {
+        // The elements after the slice are never part of a window
+        let mut vec = vec![0, 1, 2, 3];
+        assert_eq!(vec.vecslice(..2).windows(3).count(), 0);
+    }
+    #[should_panic]
+    chunks_zero => vec![0, 1].vecslice(..).chunks(0).count()
+}
+
+test! {
+    chunk_by_boundaries => {
+        // Runs are cut at the edges of the slice
+        let mut vec = vec![1, 1, 1, 1];
+        let slice = vec.vecslice(1..3);
+        assert_eq!(slice.chunk_by(|a, b| a == b).collect::<Vec<_>>(), [&[1, 1][..]]);
+    }
+}
+
+test! {
+    array_chunks_records => {
+        let mut buffer: Vec<u8> = (0..20).collect();
+        let slice = buffer.vecslice(2..18);
+        let records: Vec<u32> = slice.array_chunks::<4>().map(|r| u32::from_le_bytes(*r)).collect();
+        assert_eq!(records, [0x05040302, 0x09080706, 0x0d0c0b0a, 0x11100f0e]);
+        assert!(slice.as_chunks::<4>().1.is_empty());
+    }
+    #[should_panic]
+    as_chunks_zero => { vec![0, 1].vecslice(..).as_chunks::<0>(); }
+}
+
+test! {
+    split_edges => {
+        // A separator at the edge of the slice yields an empty subslice
+        let mut vec = vec![0, 1, 0, 2, 0];
+        let slice = vec.vecslice(..4);
+        assert_eq!(slice.split(|&x| x == 0).collect::<Vec<_>>(), [&[][..], &[1], &[2]]);
+        assert_eq!(slice.rsplit(|&x| x == 0).count(), 3);
+    }
+}
+
+test! {
+    for_each_subslice_shrink => {
+        let mut vec = vec![0, 1, 2, 3, 4, 5, 6];
+        let mut slice = vec.vecslice(1..6);
+        let mut seen = Vec::new();
+        slice.for_each_subslice(2, |sub| {
+            seen.push(sub.to_vec());
+            sub.pop_back();
+        });
+        assert_eq!(seen, [vec![1, 2], vec![3, 4], vec![5]]);
+        assert_eq!(slice, [1, 3]);
+        assert_eq!(vec, [0, 1, 3, 6]);
+    }
+    for_each_subslice_empty => {
+        let mut vec = vec![0, 1];
+        let mut slice = vec.vecslice(1..1);
+        slice.for_each_subslice(3, |_| unreachable!());
+        assert_eq!(slice, []);
+    }
+    for_each_subslice_in_shrink => {
+        let mut vec = vec![0, 1, 2, 3, 4, 5, 6];
+        let mut slice = vec.vecslice(1..6);
+        slice.for_each_subslice_in([0..2, 2..2, 4..5], |sub| sub.clear());
+        assert_eq!(slice, [3, 4]);
+        assert_eq!(vec, [0, 3, 4, 6]);
+    }
+    #[should_panic]
+    for_each_subslice_in_unsorted => vec![0, 1, 2].vecslice(..).for_each_subslice_in([1..2, 0..1], |_| ())
+    #[should_panic]
+    for_each_subslice_in_out_of_bounds => vec![0, 1, 2].vecslice(..2).for_each_subslice_in([0..1, 1..3], |_| ())
+}
+
+test! {
+    str_slice_pop => {
+        let mut string = String::from("aé€b");
+        let mut slice = vecslice::StrSlice::new(1..6, &mut string);
+        assert_eq!(slice.len(), 5);
+        assert_eq!(slice.pop(), Some('€'));
+        assert_eq!(slice.pop(), Some('é'));
+        assert_eq!(slice.pop(), None);
+        assert!(slice.is_empty());
+        assert_eq!(string, "ab");
+    }
+    str_slice_truncate => {
+        let mut string = String::from("xabcx");
+        let mut slice = vecslice::StrSlice::from(&mut string);
+        slice.truncate(4);
+        slice.drain(..1);
+        assert_eq!(slice.to_string(), "abc");
+        slice.clear();
+        assert_eq!(string, "");
+    }
+    #[should_panic]
+    str_slice_new_not_boundary => vecslice::StrSlice::new(1.., &mut String::from("é")).len()
+    #[should_panic]
+    str_slice_insert_not_boundary => vecslice::StrSlice::new(.., &mut String::from("é")).insert(1, 'a')
+    #[should_panic]
+    str_slice_insert_out_of_bounds => vecslice::StrSlice::new(..1, &mut String::from("ab")).insert_str(2, "c")
+}
+
+test! {
+    slice2d_remove_row => {
+        let mut vec: Vec<i32> = (0..6).collect();
+        let mut grid = vecslice::VecSlice2D::new(.., &mut vec, 2);
+        assert_eq!(grid.remove_row(1), [2, 3]);
+        assert_eq!(grid.shape(), (2, 2));
+        assert_eq!(grid.get(1, 1), Some(&5));
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(vec, [0, 1, 4, 5]);
+    }
+    slice2d_empty => {
+        let mut vec = vec![0, 1];
+        let mut grid = vecslice::VecSlice2D::new(1..1, &mut vec, 3);
+        assert_eq!(grid.rows(), 0);
+        grid.push_row([2, 3, 4]);
+        assert_eq!(vec, [0, 2, 3, 4, 1]);
+    }
+    #[should_panic]
+    slice2d_not_multiple => vecslice::VecSlice2D::new(.., &mut vec![0, 1, 2], 2).rows()
+    #[should_panic]
+    slice2d_column_out_of_bounds => vecslice::VecSlice2D::new(.., &mut vec![0, 1, 2, 3], 2)[(0, 2)]
+    #[should_panic]
+    slice2d_push_row_len => vecslice::VecSlice2D::new(.., &mut vec![0, 1], 2).push_row([2])
+    #[should_panic]
+    slice2d_row_changed_len => {
+        let mut vec = vec![0, 1, 2, 3];
+        let mut grid = vecslice::VecSlice2D::new(.., &mut vec, 2);
+        grid.row(0).push_back(4);
+        grid.row(1);
+    }
+}
+
+test! {
+    gap_buffer_backend => {
+        let mut buffer: vecslice::GapBuffer<i32> = (0..10).collect();
+        let mut slice = buffer.vecslice(2..8);
+        slice.push_front(10);
+        slice.push_back(11);
+        assert_eq!(slice.drain(2..4).collect::<Vec<_>>(), [3, 4]);
+        slice.retain(|x| x % 2 == 1);
+        slice.insert_many(1, [12, 13]);
+        assert_eq!(slice, [5, 12, 13, 7, 11]);
+        assert_eq!(slice.pop_front(), Some(5));
+        assert_eq!(buffer.len(), 8);
+        assert_eq!(buffer.into_vec(), [0, 1, 12, 13, 7, 11, 8, 9]);
+    }
+    gap_buffer_gap => {
+        let mut buffer = vecslice::GapBuffer::from(vec![0, 1, 2, 3]);
+        let mut slice = buffer.vecslice(1..3);
+        slice.insert(1, 4);
+        assert_eq!(slice.remove(2), 2);
+        assert_eq!(buffer.gap(), 3);
+        assert_eq!(format!("{buffer:?}"), "[0, 1, 4, 3]");
+        assert_eq!(buffer.gap(), 4);
+    }
+    gap_buffer_write_near_gap => {
+        let mut buffer: vecslice::GapBuffer<i32> = (0..10).collect();
+        {
+            let mut slice = buffer.vecslice(..);
+            slice.insert(3, 10);
+            slice[1] = 11;
+            slice[5] = 12;
+        }
+        // Writing after the gap only moved it past the written element
+        assert_eq!(buffer.gap(), 6);
+        buffer.vecslice(..).insert(6, 13);
+        assert_eq!(buffer.gap(), 7);
+        assert_eq!(buffer.into_vec(), [0, 11, 2, 10, 3, 12, 13, 5, 6, 7, 8, 9]);
+    }
+    #[should_panic]
+    gap_buffer_remove_out_of_bounds => vecslice::GapBuffer::from(vec![0]).vecslice(..).remove(1)
+}
+
+test! {
+    chunked_vec_backend => {
+        let mut buffer = vecslice::ChunkedVec::with_block_size(2);
+        buffer.extend(0..20);
+        let mut slice = buffer.vecslice(5..15);
+        slice.insert_many(3, 20..30);
+        slice.drain(1..8);
+        slice.retain(|x| x % 3 != 0);
+        slice.push_back(30);
+        let expected: Vec<i32> = (0..5).chain([5, 25, 26, 28, 29, 8, 10, 11, 13, 14, 30]).chain(15..20).collect();
+        assert_eq!(slice.len(), 11);
+        assert_eq!(buffer.into_vec(), expected);
+    }
+    chunked_vec_clear_range => {
+        let mut buffer = vecslice::ChunkedVec::with_block_size(1);
+        buffer.extend(0..10);
+        let mut slice = buffer.vecslice(..);
+        slice.insert(5, 10);
+        slice.truncate(8);
+        slice.drain(2..6);
+        assert_eq!(format!("{buffer:?}"), "[0, 1, 5, 6]");
+    }
+    chunked_vec_insert_after_read => {
+        use vecslice::Sliceable;
+        let mut buffer = vecslice::ChunkedVec::with_block_size(4);
+        buffer.extend(0..100);
+        let mut slice = buffer.vecslice(..);
+        assert_eq!(slice[50], 50);
+        let joined = slice.as_slice().as_ptr();
+        slice.insert(90, -1);
+        slice[10] += 100;
+        slice.insert(95, -2);
+        slice[92] *= -1;
+        // The elements before the first insertion are still in the block joined by the read
+        assert_eq!(slice.get_mut(0).map(|x| &*x as *const i32), Some(joined));
+        assert_eq!(buffer.len(), 102);
+        let expected: Vec<i32> = (0..10).chain([110]).chain(11..90).chain([-1, 90, -91]).chain(92..94).chain([-2]).chain(94..100).collect();
+        assert_eq!(buffer.as_slice(), expected);
+    }
+    chunked_vec_empty => {
+        let mut buffer = vecslice::ChunkedVec::<i32>::default();
+        let mut slice = buffer.vecslice(..);
+        assert_eq!(slice, []);
+        slice.push_front(1);
+        assert_eq!(slice.pop_back(), Some(1));
+        assert!(buffer.is_empty());
+    }
+}
+
+test! {
+    strided_len => {
+        let mut vec: Vec<i32> = (0..10).collect();
+        assert_eq!(vec.vecslice_strided(.., 3).len(), 4);
+        assert_eq!(vec.vecslice_strided(1..10, 3).len(), 3);
+        assert_eq!(vec.vecslice_strided(1..1, 3).len(), 0);
+        assert_eq!(vec.clone().vecslice_strided(.., 1).to_vec(), vec);
+    }
+    strided_get => {
+        let mut vec = vec![0, 1, 2, 3, 4];
+        let mut strided = vec.vecslice_strided(..4, 2);
+        assert_eq!(strided.get(1), Some(&2));
+        // Index 4 would be element 4 of the vector, outside of the range
+        assert_eq!(strided.get_mut(2), None);
+        assert_eq!(strided.get(usize::MAX), None);
+    }
+    #[should_panic]
+    strided_zero => vec![0, 1].vecslice_strided(.., 0).len()
+    #[should_panic]
+    strided_index_out_of_bounds => vec![0, 1, 2].vecslice_strided(..2, 2)[1]
+}
+
+test! {
+    reversed_mirrors_operations => {
+        let mut vec = vec![0, 1, 2, 3, 4];
+        let mut slice = vec.vecslice(1..4);
+        let mut rev = slice.reversed();
+        assert_eq!(rev.first(), Some(&3));
+        assert_eq!(rev.last(), Some(&1));
+        assert_eq!(rev.get(3), None);
+        assert_eq!(rev.remove(0), 3);
+        rev.push_front(6);
+        assert_eq!(rev.pop_back(), Some(1));
+        rev.extend([7, 8]);
+        rev[0] = 9;
+        assert_eq!(rev, [9, 2, 7, 8]);
+        assert_eq!(rev.to_vec(), [9, 2, 7, 8]);
+        assert_eq!(slice, [8, 7, 2, 9]);
+        assert_eq!(vec, [0, 8, 7, 2, 9, 4]);
+    }
+    reversed_empty => {
+        let mut vec = vec![0, 1];
+        let mut slice = vec.vecslice(1..1);
+        let mut rev = slice.reversed();
+        assert!(rev.is_empty());
+        assert_eq!(rev.pop_front(), None);
+        rev.insert(0, 2);
+        assert_eq!(vec, [0, 2, 1]);
+    }
+    #[should_panic]
+    reversed_remove_out_of_bounds => vec![0, 1].vecslice(..).reversed().remove(2)
+    #[should_panic]
+    reversed_insert_out_of_bounds => vec![0, 1].vecslice(..).reversed().insert(3, 0)
+}
+
+test! {
+    vecslice_ref_many => {
+        let vec = vec![0, 1, 2, 3, 4, 5];
+        let slices: Vec<_> = (0..3).map(|i| vec.vecslice_ref(i * 2..i * 2 + 2)).collect();
+        assert_eq!(slices[1], [2, 3]);
+        assert_eq!(slices[2][1], 5);
+        assert_eq!(slices[0][..], [0, 1]);
+        assert_eq!(slices[2].first(), Some(&4));
+        assert!(slices[0] < slices[1]);
+        let copy = slices[1];
+        assert_eq!(copy.subslice(1..).last(), Some(&3));
+        assert_eq!(copy.get(2), None);
+        assert_eq!(vec, [0, 1, 2, 3, 4, 5]);
+    }
+    vecslice_ref_backend => {
+        let buffer = vecslice::GapBuffer::from(vec![1, 2, 3]);
+        let slice = vecslice::VecSliceRef::new(1.., &buffer);
+        assert_eq!(slice.iter().sum::<i32>(), 5);
+        assert!(slice.contains(&3));
+    }
+    vecslice_ref_try_new => {
+        let vec = vec![1, 2, 3];
+        assert_eq!(vecslice::VecSliceRef::try_new(2..4, &vec).unwrap_err(), VecSliceError::EndOutOfBounds { end: 4, len: 3 });
+        assert_eq!(vecslice::VecSliceRef::try_new(..0, &vec).unwrap().to_vec(), []);
+    }
+    #[should_panic]
+    vecslice_ref_out_of_bounds => vec![1, 2, 3].vecslice_ref(1..4).len()
+    #[should_panic]
+    vecslice_ref_subslice_out_of_bounds => vec![1, 2, 3].vecslice_ref(1..).subslice(..3).len()
+}
+
+test! {
+    cell_disjoint_borrows => {
+        let cell = vecslice::VecSliceCell::new((0..10).collect());
+        let mut a = cell.borrow_mut(2..4);
+        let mut b = cell.borrow_mut(6..8);
+        let mut c = cell.borrow_mut(4..4);
+        a.slice().push_front(-1);
+        b.slice().clear();
+        c.slice().extend([-2, -3]);
+        assert_eq!(cell.len(), 10);
+        drop(a);
+        assert_eq!(cell.len(), 11);
+        let d = cell.borrow_mut(9..);
+        assert_eq!(d, [8, 9]);
+        drop((b, c, d));
+        assert_eq!(cell.into_inner(), [0, 1, -1, 2, 3, -2, -3, 4, 5, 8, 9]);
+    }
+    cell_overlaps => {
+        let cell = vecslice::VecSliceCell::new(vec![0, 1, 2, 3]);
+        let _a = cell.borrow_mut(1..3);
+        let _b = cell.borrow_mut(3..3);
+        let _c = cell.borrow_mut(1..1);
+        assert_eq!(cell.try_borrow_mut(3..3).unwrap_err(), VecSliceError::Overlap { start: 3, end: 3 });
+        assert_eq!(cell.try_borrow_mut(2..2).unwrap_err(), VecSliceError::Overlap { start: 2, end: 2 });
+        assert_eq!(cell.try_borrow_mut(0..2).unwrap_err(), VecSliceError::Overlap { start: 0, end: 2 });
+        assert!(cell.try_borrow_mut(..1).is_ok());
+        assert!(cell.try_borrow_mut(3..).is_ok());
+    }
+    #[should_panic]
+    cell_overlap_panics => {
+        let cell = vecslice::VecSliceCell::new(vec![0, 1, 2, 3]);
+        let _a = cell.borrow_mut(1..3);
+        let _b = cell.borrow_mut(..2);
+    }
+}
+
+test! {
+    owned_stored_in_struct => {
+        struct Line {
+            content: vecslice::VecSliceOwned<char>,
+        }
+        fn make() -> Line {
+            Line { content: vecslice::VecSliceOwned::new(1..3, vec!['[', 'a', 'b', ']']) }
+        }
+        let mut line = make();
+        line.content.push_back('c');
+        line.content.vecslice().rotate_left(1);
+        line.content[0] = 'x';
+        assert_eq!(line.content.pop_front(), Some('x'));
+        assert_eq!(line.content.range(), 1..3);
+        assert_eq!(line.content, ['c', 'a']);
+        assert_eq!(line.content.into_parts(), (vec!['[', 'c', 'a', ']'], 1..3));
+    }
+    owned_empty_window => {
+        let mut owned = vecslice::VecSliceOwned::new(2..2, vec![0, 1, 2]);
+        assert!(owned.is_empty());
+        owned.extend([3, 4]);
+        owned.remove(0);
+        assert_eq!(owned, [4]);
+        owned.clear();
+        assert_eq!(owned.into_inner(), [0, 1, 2]);
+    }
+    owned_try_new => {
+        let err = vecslice::VecSliceOwned::<i32>::try_new(..4, vec![1, 2]).unwrap_err();
+        assert_eq!(err, (VecSliceError::EndOutOfBounds { end: 4, len: 2 }, vec![1, 2]));
+    }
+    #[should_panic]
+    owned_out_of_bounds => vecslice::VecSliceOwned::new(3.., vec![1, 2]).len()
+}
+
+test! {
+    cursor_edits => {
+        let mut vec = vec![0, 1, 2, 3, 4];
+        let mut slice = vec.vecslice(1..4);
+        let mut cursor = slice.cursor(3);
+        assert_eq!(cursor.peek(), None);
+        assert!(!cursor.move_right());
+        cursor.insert_after(5);
+        assert_eq!(cursor.peek(), Some(&5));
+        assert!(cursor.move_left());
+        assert_eq!(cursor.peek_prev(), Some(&2));
+        cursor.seek(0);
+        assert_eq!(cursor.peek_prev(), None);
+        assert!(!cursor.move_left());
+        cursor.insert_before(6);
+        assert_eq!(cursor.remove_current(), Some(1));
+        assert_eq!(cursor.position(), 1);
+        assert_eq!(slice, [6, 2, 3, 5]);
+        assert_eq!(vec, [0, 6, 2, 3, 5, 4]);
+    }
+    cursor_empty => {
+        let mut vec = vec![0];
+        let mut slice = vec.vecslice(1..);
+        let mut cursor = slice.cursor(0);
+        assert_eq!(cursor.remove_current(), None);
+        assert_eq!(cursor.split_rest(), []);
+        cursor.insert_after(1);
+        assert_eq!(vec, [0, 1]);
+    }
+    #[should_panic]
+    cursor_out_of_bounds => vec![0, 1].vecslice(1..).cursor(2).position()
+    #[should_panic]
+    cursor_seek_out_of_bounds => vec![0, 1].vecslice(..).cursor(0).seek(3)
+}
+
+test! {
+    transaction_rollback_on_panic => {
+        let mut vec = vec![0, 1, 2, 3];
+        let mut slice = vec.vecslice(1..3);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            slice.transaction(|txn| {
+                txn.clear();
+                txn.extend([4, 5, 6]);
+                txn.remove(5);
+                Ok::<_, ()>(())
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(slice, [1, 2]);
+        slice.push_back(7);
+        assert_eq!(vec, [0, 1, 2, 7, 3]);
+    }
+    transaction_rollback_on_err => {
+        let mut buffer = vecslice::GapBuffer::from(vec![0, 1, 2, 3]);
+        let mut slice = buffer.vecslice(2..);
+        let result: Result<(), usize> = slice.transaction(|txn| {
+            txn.pop_back();
+            txn.insert(0, 9);
+            Err(txn.len())
+        });
+        assert_eq!(result, Err(2));
+        assert_eq!(slice, [2, 3]);
+        assert_eq!(buffer.into_vec(), [0, 1, 2, 3]);
+    }
+}
+
+test! {
+    journaled_undo_redo => {
+        let mut vec = vec![0, 1, 2, 3, 4];
+        let mut journaled = vecslice::Journaled::new(vec.vecslice(1..4));
+        journaled.push_front(5);
+        journaled.insert_slice(2, &[6, 7]);
+        assert_eq!(journaled.pop_back(), Some(3));
+        journaled.clear();
+        assert!(journaled.is_empty());
+        while journaled.undo() {}
+        assert_eq!(journaled.as_slice(), [1, 2, 3]);
+        assert!(journaled.redo());
+        assert!(journaled.redo());
+        assert_eq!(journaled.as_slice(), [5, 1, 6, 7, 2, 3]);
+        journaled.remove(1);
+        assert!(!journaled.can_redo());
+        assert!(journaled.undo());
+        journaled.clear_history();
+        assert!(!journaled.can_undo());
+        assert_eq!(journaled.pop_front(), Some(5));
+        journaled.into_inner();
+        assert_eq!(vec, [0, 1, 6, 7, 2, 3, 4]);
+    }
+    journaled_empty => {
+        let mut vec = vec![0];
+        let mut journaled = vecslice::Journaled::new(vec.vecslice(1..));
+        assert_eq!(journaled.pop_front(), None);
+        assert_eq!(journaled.drain(..), []);
+        assert!(journaled.undo());
+        assert!(!journaled.undo());
+        assert!(journaled.redo());
+        assert!(journaled.is_empty());
+    }
+}
+
+test! {
+    dirty_tracker_ranges => {
+        let mut buffer = vecslice::DirtyTracker::new((0..10).collect::<Vec<i32>>());
+        let mut slice = buffer.vecslice(2..6);
+        assert_eq!(slice.modified_range(), None);
+        assert_eq!(slice.iter().sum::<i32>(), 14);
+        assert_eq!(slice.modified_range(), None);
+        slice.swap(0, 1);
+        assert_eq!(slice.modified_range(), Some(2..6));
+        assert_eq!(buffer.take_modified_range(), Some(2..6));
+        // Removing marks up to the old end of the buffer
+        buffer.vecslice(8..).pop_back();
+        assert_eq!(buffer.take_modified_range(), Some(9..10));
+        buffer.vecslice(..4).truncate(2);
+        assert_eq!(buffer.take_modified_range(), Some(2..9));
+        buffer.vecslice(..2).splice(..1, [5]);
+        assert_eq!(buffer.take_modified_range(), Some(0..1));
+        assert_eq!(buffer.into_inner(), [5, 1, 4, 5, 6, 7, 8]);
+    }
+    dirty_tracker_drain => {
+        let mut buffer = vecslice::DirtyTracker::new(vec![0, 1, 2, 3, 4, 5]);
+        let drained: Vec<_> = buffer.vecslice(1..).drain(1..3).collect();
+        assert_eq!(drained, [2, 3]);
+        assert_eq!(buffer.modified_range(), Some(2..6));
+    }
+}
+
+test! {
+    tracked_index_in_place_edits => {
+        let mut buffer = vecslice::Tracked::new(vec![0, 1, 2, 3]);
+        let mut slice = buffer.vecslice(1..3);
+        let index = slice.track(1).unwrap();
+        assert_eq!(slice.track(2), None);
+        slice.reverse();
+        slice[0] = 5;
+        slice.splice(..0, []);
+        assert_eq!(slice.get_tracked(index), Ok(&1));
+        assert_eq!(index.index(), 1);
+        assert_eq!(index.generation(), slice.generation());
+        slice.pop_back();
+        assert_eq!(slice.get_tracked_mut(index), Err(VecSliceError::StaleIndex));
+        assert_eq!(buffer.generation(), 1);
+    }
+    tracked_index_other_slice => {
+        let mut buffer = vecslice::Tracked::new(vec![0, 1, 2, 3]);
+        let index = buffer.vecslice(1..).track(0).unwrap();
+        assert_eq!(buffer.vecslice(..).get_tracked(index), Err(VecSliceError::StaleIndex));
+        let last = buffer.vecslice(1..).track(2).unwrap();
+        assert_eq!(buffer.vecslice(1..2).get_tracked(last), Err(VecSliceError::IndexOutOfBounds { index: 2, len: 1 }));
+        assert_eq!(buffer.vecslice(1..).get_tracked(index), Ok(&1));
+    }
+}
+
+/// Backend that holds at most two elements.
+struct Pair(Vec<i32>);
+
+impl vecslice::Sliceable<i32> for Pair {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn insert(&mut self, index: usize, element: i32) {
+        assert!(self.0.len() < 2, "pair is full");
+        self.0.insert(index, element);
+    }
+
+    fn remove(&mut self, index: usize) -> i32 {
+        self.0.remove(index)
+    }
+
+    fn as_slice(&self) -> &[i32] {
+        &self.0
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [i32] {
+        &mut self.0
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        Some(2)
+    }
+}
+
+impl vecslice::TrySliceable<i32> for Pair {
+    fn try_insert(&mut self, index: usize, element: i32) -> Result<(), vecslice::CapacityError<i32>> {
+        if self.0.len() == 2 {
+            return Err(vecslice::CapacityError::new(element));
+        }
+        self.0.insert(index, element);
+        Ok(())
+    }
+}
+
+test! {
+    try_push_full => {
+        let mut pair = Pair(vec![]);
+        let mut slice = pair.vecslice(..);
+        assert_eq!(slice.try_push_back(1), Ok(()));
+        assert_eq!(slice.try_push_front(2), Ok(()));
+        assert!(slice.is_full());
+        let err = slice.try_push_back(3).unwrap_err();
+        assert_eq!(err.to_string(), "insufficient capacity");
+        assert_eq!(err.into_element(), 3);
+        assert_eq!(slice.try_push_front(4).unwrap_err().element(), &4);
+        assert_eq!(slice, [2, 1]);
+    }
+    try_push_tracked => {
+        let mut buffer = vecslice::Tracked::new(Pair(vec![1]));
+        let mut slice = buffer.vecslice(1..);
+        assert_eq!(slice.try_push_back(2), Ok(()));
+        assert!(slice.try_push_back(3).is_err());
+        assert_eq!(buffer.generation(), 1);
+    }
+    try_push_unbounded => {
+        let mut buffer = vecslice::GapBuffer::from(vec![1]);
+        let mut slice = buffer.vecslice(..0);
+        assert_eq!(slice.try_push_front(0), Ok(()));
+        assert_eq!(buffer.into_vec(), [0, 1]);
+    }
+}
+
+test! {
+    vecdeque_wrapped => {
+        let mut deque = std::collections::VecDeque::with_capacity(4);
+        deque.extend([0, 1, 2, 3]);
+        deque.pop_front();
+        deque.pop_front();
+        deque.push_back(4);
+        deque.push_back(5);
+        let mut slice = deque.vecslice(1..3);
+        assert_eq!(slice, [3, 4]);
+        slice.insert(1, 6);
+        slice.drain(..1);
+        slice.extend_front([7, 8]);
+        slice.splice(1..2, [9]);
+        assert_eq!(slice, [7, 9, 6, 4]);
+        assert_eq!(deque, [2, 7, 9, 6, 4, 5]);
+    }
+    #[should_panic]
+    vecdeque_not_contiguous => {
+        let mut deque = std::collections::VecDeque::with_capacity(2);
+        deque.push_back(1);
+        deque.push_front(0);
+        vecslice::VecSliceRef::new(.., &deque).to_vec()
+    }
+}
+
+test! {
+    #[cfg(feature = "smallvec")]
+    smallvec_backend => {
+        let mut small: smallvec::SmallVec<[i32; 5]> = smallvec::smallvec![0, 1, 2];
+        let mut slice = small.vecslice(1..);
+        slice.push_front(3);
+        slice.splice(1..2, [4, 5]);
+        slice.retain(|&x| x != 2);
+        assert_eq!(slice, [3, 4, 5]);
+        assert!(!small.spilled());
+        small.vecslice(..).extend(0..4);
+        assert!(small.spilled());
+        assert_eq!(small.as_slice(), [0, 3, 4, 5, 0, 1, 2, 3]);
+    }
+}
+
+test! {
+    #[cfg(feature = "arrayvec")]
+    arrayvec_backend => {
+        let mut array: arrayvec::ArrayVec<i32, 4> = [0, 1].into_iter().collect();
+        let mut slice = array.vecslice(1..);
+        assert_eq!(slice.remaining_capacity(), Some(2));
+        slice.splice(..0, [2, 3]);
+        assert!(slice.is_full());
+        assert_eq!(slice.try_push_back(4), Err(vecslice::CapacityError::new(4)));
+        slice.drain(1..2);
+        assert_eq!(slice.try_push_front(5), Ok(()));
+        assert_eq!(slice, [5, 2, 1]);
+        assert_eq!(array.as_slice(), [0, 5, 2, 1]);
+    }
+    #[cfg(feature = "arrayvec")]
+    #[should_panic]
+    arrayvec_overflow => {
+        let mut array: arrayvec::ArrayVec<i32, 1> = [0].into_iter().collect();
+        array.vecslice(..).push_back(1)
+    }
+}
+
+test! {
+    #[cfg(feature = "tinyvec")]
+    tinyvec_arrayvec_backend => {
+        let mut array = tinyvec::array_vec!([i32; 4] => 0, 1, 2);
+        let mut slice = array.vecslice(1..2);
+        assert_eq!(slice.try_push_front(3), Ok(()));
+        assert_eq!(slice.try_push_back(4), Err(vecslice::CapacityError::new(4)));
+        slice.splice(1..2, [5]);
+        slice.retain(|&x| x != 3);
+        assert_eq!(slice, [5]);
+        assert_eq!(array.as_slice(), [0, 5, 2]);
+    }
+    #[cfg(feature = "tinyvec")]
+    tinyvec_backend => {
+        let mut tiny = tinyvec::tiny_vec!([i32; 2] => 0, 1);
+        let mut slice = tiny.vecslice(1..);
+        slice.extend_front([2, 3]);
+        assert_eq!(slice.try_push_back(4), Ok(()));
+        slice.drain(..1);
+        assert_eq!(slice, [3, 1, 4]);
+        assert!(tiny.is_heap());
+        assert_eq!(tiny.as_slice(), [0, 3, 1, 4]);
+    }
+}
+
+test! {
+    #[cfg(feature = "heapless")]
+    heapless_backend => {
+        let mut buffer: heapless::Vec<i32, 5> = heapless::Vec::from_slice(&[0, 1, 2, 3]).unwrap();
+        let mut slice = buffer.vecslice(1..3);
+        slice.splice(..1, [4, 5]);
+        assert_eq!(slice.try_push_front(6), Err(vecslice::CapacityError::new(6)));
+        slice.drain(1..);
+        assert_eq!(slice.try_push_back(7), Ok(()));
+        assert_eq!(slice, [4, 7]);
+        assert_eq!(buffer, [0, 4, 7, 3]);
+    }
+    #[cfg(feature = "heapless")]
+    #[should_panic]
+    heapless_overflow => {
+        let mut buffer: heapless::Vec<i32, 2> = heapless::Vec::from_slice(&[0, 1]).unwrap();
+        buffer.vecslice(..1).extend([2])
+    }
+}
+
+test! {
+    #[cfg(feature = "bytes")]
+    bytes_backend => {
+        let mut frame = bytes::BytesMut::from(&b"HDR:hello;END"[..]);
+        let mut payload = frame.vecslice(4..9);
+        payload.splice(1..4, *b"ipp");
+        payload.push_back(b'!');
+        payload.remove(0);
+        payload.push_front(b'H');
+        assert_eq!(payload, *b"Hippo!");
+        assert_eq!(&frame[..], b"HDR:Hippo!;END");
+    }
+}
+
+test! {
+    #[cfg(feature = "im")]
+    im_backend => {
+        let mut vector: im::Vector<i32> = (0..10).collect();
+        let before = vector.clone();
+        let mut mirror = vecslice::VectorMirror::new(&mut vector, 3..7);
+        let mut slice = mirror.vecslice(1..);
+        slice.insert_many(1, [10, 11]);
+        slice.truncate(3);
+        assert_eq!(slice, [4, 10, 11]);
+        mirror.sync();
+        mirror.vecslice(..1).clear();
+        mirror.sync();
+        drop(mirror);
+        assert_eq!(vector, im::vector![0, 1, 2, 4, 10, 11, 7, 8, 9]);
+        assert_eq!(before, (0..10).collect());
+    }
+    #[cfg(feature = "im")]
+    #[should_panic]
+    im_out_of_bounds => drop(vecslice::VectorMirror::new(&mut im::vector![0, 1], 1..3))
+}
+
+test! {
+    forwarding_backends => {
+        let mut boxed = Box::new(vecslice::GapBuffer::from(vec![1, 2, 3]));
+        boxed.vecslice(1..2).push_back(4);
+        assert_eq!(boxed.vecslice(..), [1, 2, 4, 3]);
+
+        let mutex = std::sync::Mutex::new(vec![1, 2, 3]);
+        let mut guard = mutex.lock().unwrap();
+        guard.vecslice(..1).push_front(0);
+        drop(guard);
+        assert_eq!(mutex.into_inner().unwrap(), [0, 1, 2, 3]);
+    }
+}
+
+test! {
+    #[cfg(feature = "allocator_api")]
+    custom_allocator => {
+        let mut vec = Vec::new_in(std::alloc::System);
+        vec.extend([0, 1, 2, 3]);
+        let mut slice = vec.vecslice(1..3);
+        let mut other = Vec::new_in(std::alloc::System);
+        other.push(4);
+        slice.append(&mut other);
+        slice.remove(0);
+        assert_eq!(slice, [2, 4]);
+        assert_eq!(vec, [0, 2, 4, 3]);
+    }
+}
+
+test! {
+    #[cfg(feature = "serde")]
+    serde_window => {
+        use serde::de::IntoDeserializer;
+        use serde::de::value::{Error, SeqDeserializer};
+        use serde_test::Token;
+
+        let mut vec = vec![0, 1, 2, 3, 4];
+        let mut slice = vec.vecslice(1..4);
+        serde_test::assert_ser_tokens(&slice, &[Token::Seq { len: Some(3) }, Token::I32(1), Token::I32(2), Token::I32(3), Token::SeqEnd]);
+
+        let invalid: SeqDeserializer<_, Error> = vec![5, i64::MAX].into_deserializer();
+        assert!(slice.deserialize_in_place(invalid).is_err());
+        assert_eq!(slice, [1, 2, 3]);
+        let empty: SeqDeserializer<_, Error> = Vec::<i32>::new().into_deserializer();
+        slice.deserialize_in_place(empty).unwrap();
+        assert!(slice.is_empty());
+        let valid: SeqDeserializer<_, Error> = vec![7, 8].into_deserializer();
+        slice.deserialize_in_place(valid).unwrap();
+        assert_eq!(vec, [0, 7, 8, 4]);
+    }
+}
+
+test! {
+    #[cfg(feature = "rayon")]
+    rayon_par_iter => {
+        use rayon::prelude::*;
+
+        let mut vec: Vec<i32> = (0..100).collect();
+        let mut slice = vec.vecslice(10..20);
+        slice.par_iter_mut().for_each(|x| *x = -*x);
+        assert_eq!(slice.par_iter().copied().max(), Some(-10));
+        assert_eq!(vec[9..11], [9, -10]);
+        assert_eq!(vec[19..21], [-19, 20]);
+    }
+}
+
+test! {
+    #[cfg(feature = "rand")]
+    rand_window => {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::SmallRng::seed_from_u64(42);
+        let mut vec: Vec<i32> = (0..50).collect();
+        let mut slice = vec.vecslice(10..40);
+        slice.shuffle(&mut rng);
+        let (sample, rest) = slice.partial_shuffle(&mut rng, 5);
+        assert!(sample.iter().chain(rest.iter()).all(|x| (10..40).contains(x)));
+        assert!((10..40).contains(slice.choose(&mut rng).unwrap()));
+        assert!(vec.vecslice(50..).choose_mut(&mut rng).is_none());
+        assert_eq!(vec[..10], (0..10).collect::<Vec<_>>());
+        assert_eq!(vec[40..], (40..50).collect::<Vec<_>>());
+        vec.sort();
+        assert_eq!(vec, (0..50).collect::<Vec<_>>());
+    }
+}
+
+test! {
+    #[cfg(feature = "arbitrary")]
+    arbitrary_ops_match_model => {
+        use arbitrary::{Arbitrary, Unstructured};
+        use vecslice::fuzz::{apply, apply_model, Op};
+
+        let data: Vec<u8> = (0..=255u8).map(|x| x.wrapping_mul(167).rotate_left(3)).collect();
+        let mut u = Unstructured::new(&data);
+        let mut vec = vec![-1, 1, 2, 3, -1];
+        let mut slice = vec.vecslice(1..4);
+        let mut model = vec![1, 2, 3];
+        let mut applied = 0;
+        while let Ok(op) = Op::<i16>::arbitrary(&mut u) {
+            assert_eq!(apply(&mut slice, op.clone()), apply_model(&mut model, op));
+            assert_eq!(slice, model);
+            applied += 1;
+            if u.is_empty() {
+                break;
+            }
+        }
+        assert!(applied > 10);
+        assert_eq!(slice, model);
+        assert_eq!(vec[0], -1);
+        assert_eq!(vec.last(), Some(&-1));
+    }
+}
+
+#[cfg(feature = "proptest")]
+mod strategies {
+    use proptest::prelude::*;
+    use vecslice::strategies::{check_model, ops, vec_and_range};
+
+    proptest! {
+        #[test]
+        fn gap_buffer_matches_model((vec, range) in vec_and_range(any::<i8>(), 0..40), ops in ops(any::<i8>(), 0..40)) {
+            check_model(&mut vecslice::GapBuffer::from(vec), range, ops)?;
+        }
+
+        #[test]
+        fn chunked_vec_matches_model((vec, range) in vec_and_range(any::<i8>(), 0..40), ops in ops(any::<i8>(), 0..40)) {
+            check_model(&mut vecslice::ChunkedVec::from(vec), range, ops)?;
+        }
+    }
+}
+
+test! {
+    io_write_appends => {
+        use std::io::Write;
+
+        let mut buffer = vec![b'<', b'>'];
+        let mut slice = buffer.vecslice(1..1);
+        slice.write_all(b"abc").unwrap();
+        assert_eq!(slice.write(b"").unwrap(), 0);
+        std::io::copy(&mut &b"def"[..], &mut slice).unwrap();
+        slice.flush().unwrap();
+        assert_eq!(slice, *b"abcdef");
+        assert_eq!(buffer, b"<abcdef>");
+    }
+}
+
+test! {
+    io_read_consumes_front => {
+        use std::io::Read;
+
+        let mut buffer = b"<abcdef>".to_vec();
+        let mut slice = buffer.vecslice(1..7);
+        let mut chunk = [0; 4];
+        assert_eq!(slice.read(&mut chunk).unwrap(), 4);
+        assert_eq!(&chunk, b"abcd");
+        let mut rest = Vec::new();
+        slice.read_to_end(&mut rest).unwrap();
+        assert_eq!(rest, b"ef");
+        assert_eq!(slice.read(&mut chunk).unwrap(), 0);
+        slice.push_back(b'!');
+        assert_eq!(buffer, b"<!>");
+    }
+}
+
+test! {
+    fmt_write_appends => {
+        use std::fmt::Write;
+
+        let mut buffer = vec![0, 0];
+        let mut slice = buffer.vecslice(1..1);
+        write!(slice, "{:>3}", 'ñ').unwrap();
+        slice.write_char('!').unwrap();
+        assert_eq!(std::str::from_utf8(slice.as_slice()), Ok("  ñ!"));
+        assert_eq!(buffer.len(), 7);
+    }
+}
+
+test! {
+    #[cfg(feature = "bytemuck")]
+    bytemuck_cast => {
+        let mut vec = vec![0u32; 4];
+        let mut slice = vec.vecslice(1..3);
+        slice.cast_vecslice::<u8>().copy_from_slice(&[1; 8]);
+        assert_eq!(slice.try_cast_vecslice::<[u8; 3]>(), Err(bytemuck::PodCastError::OutputSliceWouldHaveSlop));
+        assert_eq!(slice.try_cast_vecslice::<[u8; 4]>().map(|s| s.len()), Ok(2));
+        assert_eq!(vec, [0, 0x01010101, 0x01010101, 0]);
+    }
+}
+
+test! {
+    hash_matches_slice => {
+        use std::collections::HashSet;
+        use std::hash::BuildHasher;
+
+        let state = std::collections::hash_map::RandomState::new();
+        let mut vec = vec![0, 1, 2, 3];
+        let slice = vec.vecslice(1..3);
+        assert_eq!(state.hash_one(&slice), state.hash_one([1, 2].as_slice()));
+
+        let mut seen: HashSet<vecslice::VecSlice<i32>> = HashSet::new();
+        seen.insert(slice);
+        assert!(seen.contains([1, 2].as_slice()));
+    }
+}
+
+test! {
+    symmetric_comparisons => {
+        let mut a = vec![3, 1, 2];
+        let mut b = vec![1, 2, 3];
+        let first = a.vecslice(1..);
+        let second = b.vecslice(..2);
+        assert_eq!([1, 2], first);
+        assert_eq!(vec![1, 2], first);
+        assert_eq!(&[1, 2][..], first);
+        assert!(*[1, 2].as_slice() == first);
+        assert_eq!(first.cmp(&second), std::cmp::Ordering::Equal);
+
+        let mut c = vec![1, 3];
+        let third = c.vecslice(..);
+        assert_eq!(first.cmp(&third), std::cmp::Ordering::Less);
+        assert_eq!([&first, &third].iter().max(), Some(&&third));
+    }
+}
+
+test! {
+    collect_vecslice_at_tail => {
+        let mut deque = std::collections::VecDeque::from([1, 2]);
+        let mut section = deque.collect_vecslice_at_tail([3, 4, 5].iter().map(|x| x * 2).filter(|x| *x != 8));
+        assert_eq!(section, [6, 10]);
+        section.pop_front();
+        assert_eq!(deque, [1, 2, 10]);
+
+        let mut vec: Vec<i32> = Vec::new();
+        assert!(vec.collect_vecslice_at_tail(std::iter::empty()).is_empty());
+        vec.collect_vecslice_at_tail(0..100);
+        assert!(vec.capacity() >= 100);
+    }
+}
+
+test! {
+    extend_by_reference => {
+        let mut vec = Vec::with_capacity(2);
+        vec.extend([0, 9]);
+        let mut slice = vec.vecslice(1..1);
+        slice.extend(&[1, 2]);
+        slice.extend([3, 4].iter());
+        slice.extend(&vec![5; 3]);
+        assert_eq!(slice, [1, 2, 3, 4, 5, 5, 5]);
+        assert_eq!(vec, [0, 1, 2, 3, 4, 5, 5, 5, 9]);
+
+        let mut tracker = vecslice::DirtyTracker::new(vec![0u8; 4]);
+        tracker.vecslice(4..).extend(b"ab");
+        assert_eq!(tracker.take_modified_range(), Some(4..6));
+    }
+}
+
+test! {
+    compact_debug => {
+        let mut vec: Vec<u32> = (0..1000).collect();
+        let slice = vec.vecslice(10..12);
+        assert_eq!(format!("{slice:?}"), "VecSlice { slice: [10, 11], start: 10, end: 12, original_len: 1000 }");
+        assert!(format!("{:?}", slice.debug_verbose()).ends_with("998, 999] }"));
+        assert_eq!(format!("{:?}", vec.vecslice_ref(..1)), "VecSliceRef { slice: [0], start: 0, end: 1, original_len: 1000 }");
+    }
+}
+
+test! {
+    window_introspection => {
+        let mut vec = vec![0; 10];
+        let mut slice = vec.vecslice(2..5);
+        slice.drain(..2);
+        slice.push_front(1);
+        slice.push_front(1);
+        assert_eq!((slice.start(), slice.end()), (2, 5));
+        let mut tail = slice.new_at_tail();
+        tail.push_back(2);
+        assert_eq!(tail.range(), 5..6);
+        assert_eq!(tail.original_len(), 11);
+        assert_eq!(tail.range().len(), tail.len());
+    }
+}
+
+test! {
+    grow_and_shrink => {
+        let mut vec = vec![0, 1, 2, 3, 4, 5];
+        let mut slice = vec.vecslice(2..4);
+        slice.grow_front(2);
+        slice.grow_back(2);
+        assert_eq!(slice, [0, 1, 2, 3, 4, 5]);
+        slice.shrink_front(6);
+        assert!(slice.is_empty());
+        assert_eq!(slice.range(), 6..6);
+        slice.grow_front(3);
+        slice.shrink_back(1);
+        slice.push_back(9);
+        assert_eq!(slice, [3, 4, 9]);
+        assert_eq!(vec, [0, 1, 2, 3, 4, 9, 5]);
+    }
+    #[should_panic]
+    grow_front_past_start => {
+        let mut vec = vec![0, 1, 2];
+        vec.vecslice(1..).grow_front(2)
+    }
+    #[should_panic]
+    grow_back_past_end => {
+        let mut vec = vec![0, 1, 2];
+        vec.vecslice(..2).grow_back(2)
+    }
+    grow_within_lent_slices => {
+        let mut vec: Vec<i32> = (0..8).collect();
+        let mut slice = vec.vecslice(1..7);
+        slice.for_each_subslice(2, |sub| {
+            sub.shrink_front(1);
+            sub.push_front(-1);
+            sub.grow_front(1);
+            sub.shrink_back(1);
+        });
+        assert_eq!(slice, [1, -1, 2, 3, -1, 4, 5, -1, 6]);
+
+        let mut split = slice.split_at(3);
+        let mut left = split.left();
+        left.shrink_back(2);
+        left.pop_back();
+        left.grow_back(2);
+        assert_eq!(left, [-1, 2]);
+        split.right().pop_front();
+        assert_eq!(split.join(), [-1, 2, -1, 4, 5, -1, 6]);
+
+        let mut parts = vec.vecslice(2..6).into_split([0..1, 2..4]);
+        let mut part = parts.part(1);
+        part.shrink_front(2);
+        part.grow_front(1);
+        part.insert(0, 9);
+        assert_eq!(parts.join(), [2, -1, 4, 9, 5]);
+        assert_eq!(vec, [0, -1, 2, -1, 4, 9, 5, -1, 6, 7]);
+    }
+    #[should_panic]
+    grow_front_past_subslice => {
+        let mut vec: Vec<i32> = (0..6).collect();
+        vec.vecslice(..).for_each_subslice(2, |sub| {
+            if sub.start() > 0 {
+                sub.grow_front(1);
+            }
+        })
+    }
+    #[should_panic]
+    grow_back_past_split_half => {
+        let mut vec = vec![0, 1, 2, 3];
+        vec.vecslice(..).split_at(2).left().grow_back(1);
+    }
+    #[should_panic]
+    grow_front_past_split_part => {
+        let mut vec = vec![0, 1, 2, 3];
+        vec.vecslice(1..).into_split([0..1, 1..2]).part(1).grow_front(2);
+    }
+    #[should_panic]
+    grow_back_past_owned_window => {
+        let mut owned = vecslice::VecSliceOwned::new(1..2, vec![0, 1, 2]);
+        owned.vecslice().grow_back(1)
+    }
+    transaction_bounds_growth => {
+        let mut vec = vec![0, 1, 2, 3, 4];
+        let mut slice = vec.vecslice(1..4);
+        let result = slice.transaction(|txn| {
+            txn.shrink_front(2);
+            txn.clear();
+            txn.grow_front(1);
+            txn.push_back(9);
+            Err::<(), _>(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| txn.grow_front(2))).is_err())
+        });
+        assert_eq!(result, Err(true));
+        assert_eq!(slice, [1, 2, 3]);
+        assert_eq!(vec, [0, 1, 2, 3, 4]);
+    }
+    #[should_panic]
+    shrink_past_len => {
+        let mut vec = vec![0, 1, 2];
+        vec.vecslice(1..2).shrink_back(2)
+    }
+}
+
+test! {
+    shift_window => {
+        let mut vec: Vec<i32> = (0..9).collect();
+        let mut window = vec.vecslice(..3);
+        while window.end() < window.original_len() {
+            window[0] *= -1;
+            window.shift_right(3);
+        }
+        window[0] *= -1;
+        window.shift_left(6);
+        window.push_back(100);
+        assert_eq!(vec, [0, 1, 2, 100, -3, 4, 5, -6, 7, 8]);
+    }
+    shift_within_lent_slices => {
+        let mut vec: Vec<i32> = (0..8).collect();
+        let mut slice = vec.vecslice(1..7);
+        slice.for_each_subslice(3, |sub| {
+            sub.shrink_back(1);
+            sub.shift_right(1);
+            (*sub)[0] *= -1;
+            sub.push_back(9);
+        });
+        assert_eq!(slice, [1, -2, 3, 9, 4, -5, 6, 9]);
+
+        let mut split = slice.split_at(4);
+        let mut right = split.right();
+        right.shrink_front(2);
+        right.shift_left(2);
+        right.remove(0);
+        assert_eq!(right, [-5]);
+        assert_eq!(split.join(), [1, -2, 3, 9, -5, 6, 9]);
+
+        let mut parts = vec.vecslice(1..5).into_split([0..2, 2..4]);
+        let mut part = parts.part(0);
+        part.shrink_back(1);
+        part.shift_right(1);
+        part.pop_front();
+        assert_eq!(parts.join(), [1, 3, 9]);
+        assert_eq!(vec, [0, 1, 3, 9, -5, 6, 9, 7]);
+    }
+    #[should_panic]
+    shift_right_past_subslice => {
+        let mut vec: Vec<i32> = (0..6).collect();
+        vec.vecslice(..5).for_each_subslice(2, |sub| sub.shift_right(1))
+    }
+    #[should_panic]
+    shift_right_past_split_half => {
+        let mut vec = vec![0, 1, 2, 3];
+        vec.vecslice(..).split_at(2).left().shift_right(1);
+    }
+    #[should_panic]
+    shift_left_past_split_part => {
+        let mut vec = vec![0, 1, 2, 3];
+        vec.vecslice(..).into_split([0..1, 2..3]).part(1).shift_left(1);
+    }
+    #[should_panic]
+    shift_right_past_end => {
+        let mut vec = vec![0, 1, 2];
+        vec.vecslice(1..2).shift_right(2)
+    }
+    #[should_panic]
+    shift_left_past_start => {
+        let mut vec = vec![0, 1, 2];
+        vec.vecslice(1..2).shift_left(2)
+    }
+}
+
+test! {
+    reslice_keeps_lifetime => {
+        fn middle(vec: &mut Vec<i32>) -> vecslice::VecSlice<'_, i32> {
+            vec.vecslice(1..).reslice(1..=2)
+        }
+        let mut vec = vec![0, 1, 2, 3, 4];
+        let mut slice = middle(&mut vec);
+        assert_eq!(slice, [2, 3]);
+        slice.clear();
+        assert_eq!(vec, [0, 1, 4]);
+        assert!(vec.vecslice(1..).reslice(2..).is_empty());
+    }
+    #[should_panic]
+    reslice_out_of_bounds => {
+        let mut vec = vec![0, 1, 2, 3];
+        vec.vecslice(1..3).reslice(..3);
+    }
+}
+
+test! {
+    into_split_parts => {
+        let mut vec: Vec<i32> = (0..10).collect();
+        let mut parts = vec.vecslice(2..8).into_split([0..2, 2..2, 4..6]);
+        parts.part(2).clear();
+        parts.part(1).extend([-1, -2]);
+        parts.part(0).remove(0);
+        assert_eq!(parts.part(1), [-1, -2]);
+        assert!(parts.part(2).is_empty());
+        parts.part(2).push_back(-3);
+        let slice = parts.join();
+        assert_eq!(slice, [3, -1, -2, 4, 5, -3]);
+        assert_eq!(vec, [0, 1, 3, -1, -2, 4, 5, -3, 8, 9]);
+    }
+    #[should_panic]
+    into_split_overlapping => {
+        let mut vec = vec![0, 1, 2, 3];
+        vec.vecslice(..).into_split([0..2, 1..3]);
+    }
+}
+